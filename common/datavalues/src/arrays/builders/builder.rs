@@ -5,17 +5,21 @@
 use std::sync::Arc;
 
 use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::ArrayData;
 use common_arrow::arrow::array::ArrayRef;
-use common_arrow::arrow::array::BinaryBuilder;
+use common_arrow::arrow::array::BinaryArray;
 use common_arrow::arrow::array::BooleanBuilder;
 use common_arrow::arrow::array::ListBuilder;
 use common_arrow::arrow::array::PrimitiveBuilder;
 use common_arrow::arrow::array::StringBuilder;
 use common_arrow::arrow::buffer::Buffer;
+use common_arrow::arrow::buffer::MutableBuffer;
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
 use num::Num;
 
 use super::ArrowBooleanArrayBuilder;
 use super::ArrowPrimitiveArrayBuilder;
+use super::BooleanBufferBuilder;
 use crate::arrays::DataArray;
 use crate::arrays::GetValues;
 use crate::data_df_type::*;
@@ -287,6 +291,8 @@ pub trait ListBuilderTrait {
     fn append_series(&mut self, s: &Series);
     fn append_null(&mut self);
     fn finish(&mut self) -> DFListArray;
+    /// The data type of the list values this builder appends.
+    fn data_type(&self) -> DataType;
 }
 
 pub struct ListPrimitiveArrayBuilder<T>
@@ -374,6 +380,10 @@ where
     fn finish(&mut self) -> DFListArray {
         finish_list_builder!(self)
     }
+
+    fn data_type(&self) -> DataType {
+        T::data_type()
+    }
 }
 
 pub struct ListUtf8ArrayBuilder {
@@ -420,6 +430,10 @@ impl ListBuilderTrait for ListUtf8ArrayBuilder {
     fn finish(&mut self) -> DFListArray {
         finish_list_builder!(self)
     }
+
+    fn data_type(&self) -> DataType {
+        DataType::Utf8
+    }
 }
 
 pub struct ListBooleanArrayBuilder {
@@ -467,6 +481,10 @@ impl ListBuilderTrait for ListBooleanArrayBuilder {
     fn finish(&mut self) -> DFListArray {
         finish_list_builder!(self)
     }
+
+    fn data_type(&self) -> DataType {
+        DataType::Boolean
+    }
 }
 
 pub fn get_list_builder(
@@ -490,7 +508,8 @@ pub fn get_list_builder(
     }
     macro_rules! get_utf8_builder {
         () => {{
-            let values_builder = StringBuilder::with_capacity(value_capacity * 5, value_capacity);
+            let values_builder =
+                StringBuilder::with_capacity(value_capacity.saturating_mul(5), value_capacity);
             let builder = ListUtf8ArrayBuilder::new(values_builder, list_capacity);
             Box::new(builder)
         }};
@@ -504,26 +523,71 @@ pub fn get_list_builder(
 }
 
 pub struct BinaryArrayBuilder {
-    builder: BinaryBuilder,
+    offsets: MutableBuffer,
+    values: MutableBuffer,
+    bitmap_builder: BooleanBufferBuilder,
+    len: usize,
+    null_count: usize,
 }
 
 impl BinaryArrayBuilder {
+    /// Create a new BinaryArrayBuilder
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Number of bytes needed to store the binary values.
     pub fn new(capacity: usize) -> Self {
+        Self::with_capacity(1024, capacity)
+    }
+
+    /// Create a new BinaryArrayBuilder
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Number of binary elements in the final array.
+    /// * `bytes_capacity` - Number of bytes needed to store the binary values.
+    pub fn with_capacity(capacity: usize, bytes_capacity: usize) -> Self {
+        let mut offsets = MutableBuffer::new((capacity + 1) * std::mem::size_of::<i32>());
+        offsets.push(0i32);
         Self {
-            builder: BinaryBuilder::new(capacity),
+            offsets,
+            values: MutableBuffer::new(bytes_capacity),
+            bitmap_builder: BooleanBufferBuilder::new(capacity),
+            len: 0,
+            null_count: 0,
         }
     }
 
     pub fn append_value(&mut self, value: impl AsRef<[u8]>) {
-        self.builder.append_value(value).unwrap();
+        self.values.extend_from_slice(value.as_ref());
+        self.offsets.push(self.values.len() as i32);
+        self.bitmap_builder.append(true);
+        self.len += 1;
     }
 
     pub fn append_null(&mut self) {
-        self.builder.append_null().unwrap();
+        self.offsets.push(self.values.len() as i32);
+        self.bitmap_builder.append(false);
+        self.len += 1;
+        self.null_count += 1;
     }
 
     pub fn finish(&mut self) -> DataArray<BinaryType> {
-        let array = self.builder.finish();
-        DFBinaryArray::from_arrow_array(array)
+        let offsets = std::mem::replace(&mut self.offsets, MutableBuffer::new(0));
+        let values = std::mem::replace(&mut self.values, MutableBuffer::new(0));
+        let null_bit_buffer = self.bitmap_builder.finish();
+
+        let mut builder = ArrayData::builder(ArrowDataType::Binary)
+            .len(self.len)
+            .add_buffer(offsets.into())
+            .add_buffer(values.into());
+        if self.null_count > 0 {
+            builder = builder.null_bit_buffer(null_bit_buffer);
+        }
+
+        self.offsets.push(0i32);
+        self.len = 0;
+        self.null_count = 0;
+        DFBinaryArray::from_arrow_array(BinaryArray::from(builder.build()))
     }
 }
@@ -0,0 +1,88 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::arrays::Utf8ArrayBuilder;
+use crate::DFFloat64Array;
+use crate::DFUtf8Array;
+
+const GEOHASH_BASE32: &[u8] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+const GEOHASH_MAX_PRECISION: usize = 12;
+
+/// Encode every (lat, lon) pair into a geohash of `precision` characters,
+/// null if either coordinate is null.
+/// Returns a BadArguments error if the arrays have different lengths, if the precision
+/// is not in the range [1, 12], or if a coordinate is out of range or NaN.
+pub fn geohash_encode(
+    lat: &DFFloat64Array,
+    lon: &DFFloat64Array,
+    precision: usize,
+) -> Result<DFUtf8Array> {
+    if lat.len() != lon.len() {
+        return Err(ErrorCode::BadArguments(format!(
+            "Geohash latitude and longitude must have the same length, got: {}, expect: {}",
+            lon.len(),
+            lat.len()
+        )));
+    }
+
+    if precision == 0 || precision > GEOHASH_MAX_PRECISION {
+        return Err(ErrorCode::BadArguments(format!(
+            "Geohash precision must be in the range [1, {}], got: {}",
+            GEOHASH_MAX_PRECISION, precision
+        )));
+    }
+
+    let mut builder = Utf8ArrayBuilder::new(lat.len(), lat.len() * precision);
+    for (lat, lon) in lat.downcast_iter().zip(lon.downcast_iter()) {
+        match (lat, lon) {
+            (Some(lat), Some(lon)) => builder.append_value(geohash(lat, lon, precision)?),
+            _ => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+fn geohash(lat: f64, lon: f64, precision: usize) -> Result<String> {
+    if !(-90.0..=90.0).contains(&lat) {
+        return Err(ErrorCode::BadArguments(format!(
+            "Geohash latitude {} is out of range [-90, 90]",
+            lat
+        )));
+    }
+    if !(-180.0..=180.0).contains(&lon) {
+        return Err(ErrorCode::BadArguments(format!(
+            "Geohash longitude {} is out of range [-180, 180]",
+            lon
+        )));
+    }
+
+    let mut lat_range = (-90.0, 90.0);
+    let mut lon_range = (-180.0, 180.0);
+    let mut hash = String::with_capacity(precision);
+    // Bits alternate between longitude and latitude, starting with longitude.
+    let mut even = true;
+    while hash.len() < precision {
+        let mut index = 0;
+        for _ in 0..5 {
+            let (value, range) = match even {
+                true => (lon, &mut lon_range),
+                false => (lat, &mut lat_range),
+            };
+            let mid = (range.0 + range.1) / 2.0;
+            index <<= 1;
+            if value >= mid {
+                index |= 1;
+                range.0 = mid;
+            } else {
+                range.1 = mid;
+            }
+            even = !even;
+        }
+        hash.push(GEOHASH_BASE32[index] as char);
+    }
+    Ok(hash)
+}
@@ -0,0 +1,43 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_exception::Result;
+
+use crate::arrays::geohash_encode;
+use crate::prelude::*;
+use crate::DFFloat64Array;
+
+#[test]
+fn test_geohash_encode() -> Result<()> {
+    let lat = DFFloat64Array::new_from_opt_slice(&[Some(57.64911), Some(42.6), None]);
+    let lon = DFFloat64Array::new_from_opt_slice(&[Some(10.40744), Some(-5.6), Some(1.0)]);
+
+    let hashes = geohash_encode(&lat, &lon, 11)?;
+    assert_eq!(
+        vec![Some("u4pruydqqvj"), Some("ezs42e44yx9"), None],
+        hashes.collect_values()
+    );
+
+    let hashes = geohash_encode(&lat, &lon, 5)?;
+    assert_eq!(
+        vec![Some("u4pru"), Some("ezs42"), None],
+        hashes.collect_values()
+    );
+
+    let lat = DFFloat64Array::new_from_slice(&[91.0]);
+    let lon = DFFloat64Array::new_from_slice(&[0.0]);
+    let result = geohash_encode(&lat, &lon, 5);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Geohash latitude 91 is out of range [-90, 90]"
+    );
+
+    let result = geohash_encode(&lon, &lon, 13);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Geohash precision must be in the range [1, 12], got: 13"
+    );
+
+    Ok(())
+}
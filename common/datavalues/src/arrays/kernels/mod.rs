@@ -2,8 +2,13 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
+mod geohash;
 mod iterator;
 mod take;
 
+#[cfg(test)]
+mod geohash_test;
+
+pub use geohash::*;
 pub use iterator::*;
 pub use take::*;
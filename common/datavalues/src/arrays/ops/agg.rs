@@ -126,6 +126,38 @@ where
     }
 }
 
+impl<T> DataArray<T>
+where
+    T: DFNumericType,
+    T::Native: Num + Into<DataValue>,
+{
+    /// Aggregate the sum of the rows whose mask is true, as in `SUM(x) FILTER (WHERE cond)`.
+    /// Null values and null mask entries are skipped.
+    /// Returns `DataValue::Null` if no non-null value is selected.
+    pub fn sum_filtered(&self, mask: &DFBooleanArray) -> Result<DataValue> {
+        if mask.len() != self.len() {
+            return Err(ErrorCode::BadDataArrayLength(format!(
+                "cannot sum filtered array, mask size not matched, got: {}, expect: {}",
+                mask.len(),
+                self.len(),
+            )));
+        }
+
+        let sum = self.downcast_iter().zip(mask.downcast_iter()).fold(
+            None,
+            |acc: Option<T::Native>, (value, selected)| match (value, selected) {
+                (Some(v), Some(true)) => Some(acc.map_or(v, |acc| acc + v)),
+                _ => acc,
+            },
+        );
+
+        Ok(match sum {
+            Some(x) => x.into(),
+            None => DataValue::from(self.data_type()),
+        })
+    }
+}
+
 impl ArrayAgg for DFBooleanArray {
     fn sum(&self) -> Result<DataValue> {
         if self.all_is_null() {
@@ -97,3 +97,28 @@ fn test_utf8_array_agg() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_array_sum_filtered() -> Result<()> {
+    let array = DFUInt16Array::new_from_opt_slice(&[Some(1u16), Some(2), None, Some(4), Some(8)]);
+    let mask = DFBooleanArray::new_from_opt_slice(&[
+        Some(true),
+        Some(false),
+        Some(true),
+        None,
+        Some(true),
+    ]);
+    assert_eq!(array.sum_filtered(&mask)?, DataValue::UInt16(Some(9)));
+
+    let mask = DFBooleanArray::new_from_slice(&[false, false, true, false, false]);
+    assert_eq!(array.sum_filtered(&mask)?, DataValue::UInt16(None));
+
+    let mask = DFBooleanArray::new_from_slice(&[true]);
+    let result = array.sum_filtered(&mask);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "cannot sum filtered array, mask size not matched, got: 1, expect: 5"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,16 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use crate::DFBinaryArray;
+use crate::DFUInt32Array;
+
+impl DFBinaryArray {
+    /// Compute the CRC32 (IEEE) checksum of every binary value, null values stay null.
+    pub fn crc32(&self) -> DFUInt32Array {
+        self.downcast_ref()
+            .iter()
+            .map(|value| value.map(crc32fast::hash))
+            .collect()
+    }
+}
@@ -0,0 +1,25 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_arrow::arrow::array::BinaryArray;
+
+use crate::prelude::*;
+use crate::DFBinaryArray;
+
+#[test]
+fn test_binary_crc32() {
+    let arrow_binary_array = BinaryArray::from_opt_vec(vec![
+        Some(&b"123456789"[..]),
+        None,
+        Some(&b""[..]),
+        Some(&b"abc"[..]),
+    ]);
+    let df_binary_array = DFBinaryArray::from_arrow_array(arrow_binary_array);
+    let crc = df_binary_array.crc32();
+
+    assert_eq!(
+        vec![Some(0xCBF4_3926u32), None, Some(0), Some(0x3524_41C2)],
+        crc.collect_values()
+    );
+}
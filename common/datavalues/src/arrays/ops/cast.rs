@@ -5,7 +5,9 @@
 use common_arrow::arrow::compute::cast;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use num::Bounded;
 use num::NumCast;
+use num::ToPrimitive;
 
 use crate::arrays::DataArray;
 use crate::data_df_type::*;
@@ -84,6 +86,62 @@ where
     }
 }
 
+fn cast_saturating_ca<N, T>(ca: &DataArray<T>) -> DataArray<N>
+where
+    N: DFNumericType,
+    N::Native: NumCast + Bounded,
+    T: DFNumericType,
+    T::Native: NumCast,
+{
+    ca.downcast_iter()
+        .map(|opt_v| {
+            opt_v.and_then(|v| {
+                let f = v.to_f64()?;
+                if f.is_nan() {
+                    return None;
+                }
+
+                match NumCast::from(v) {
+                    // Float targets overflow to infinity instead of failing, clamp those too.
+                    Some(v) if N::Native::to_f64(&v).map_or(false, f64::is_finite) => Some(v),
+                    // Out of range, clamp towards the side it overflows.
+                    _ if f > 0.0 => Some(N::Native::max_value()),
+                    _ => Some(N::Native::min_value()),
+                }
+            })
+        })
+        .collect()
+}
+
+impl<T> DataArray<T>
+where
+    T: DFNumericType,
+    T::Native: NumCast,
+{
+    /// Cast to a numeric `data_type`, clamping out-of-range values to the min/max
+    /// of the target type instead of failing, e.g. -1 as UInt8 is 0 and 300 as UInt8 is 255.
+    /// The same holds for float targets, where overflow and infinities clamp to the finite
+    /// min/max, e.g. 1e300 as Float32 is f32::MAX. Null and NaN values become null.
+    pub fn cast_saturating(&self, data_type: &DataType) -> Result<Series> {
+        match data_type {
+            DataType::UInt8 => Ok(cast_saturating_ca::<UInt8Type, T>(self).into_series()),
+            DataType::UInt16 => Ok(cast_saturating_ca::<UInt16Type, T>(self).into_series()),
+            DataType::UInt32 => Ok(cast_saturating_ca::<UInt32Type, T>(self).into_series()),
+            DataType::UInt64 => Ok(cast_saturating_ca::<UInt64Type, T>(self).into_series()),
+            DataType::Int8 => Ok(cast_saturating_ca::<Int8Type, T>(self).into_series()),
+            DataType::Int16 => Ok(cast_saturating_ca::<Int16Type, T>(self).into_series()),
+            DataType::Int32 => Ok(cast_saturating_ca::<Int32Type, T>(self).into_series()),
+            DataType::Int64 => Ok(cast_saturating_ca::<Int64Type, T>(self).into_series()),
+            DataType::Float32 => Ok(cast_saturating_ca::<Float32Type, T>(self).into_series()),
+            DataType::Float64 => Ok(cast_saturating_ca::<Float64Type, T>(self).into_series()),
+            dt => Err(ErrorCode::BadDataValueType(format!(
+                "Unsupported apply cast_saturating operation to {:?}",
+                dt
+            ))),
+        }
+    }
+}
+
 impl ArrayCast for DataArray<Utf8Type> {
     fn cast<N>(&self) -> Result<DataArray<N>>
     where N: DFDataType {
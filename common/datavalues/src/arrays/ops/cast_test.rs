@@ -5,7 +5,10 @@
 use common_exception::Result;
 
 use crate::prelude::*;
+use crate::DFFloat64Array;
+use crate::DFInt16Array;
 use crate::DFUInt16Array;
+use crate::DFUInt64Array;
 
 #[test]
 fn test_array_cast() -> Result<()> {
@@ -15,3 +18,54 @@ fn test_array_cast() -> Result<()> {
     assert!(result.series_equal(&expected));
     Ok(())
 }
+
+#[test]
+fn test_array_cast_saturating() -> Result<()> {
+    let array = DFInt16Array::new_from_opt_slice(&[Some(-1), Some(300), None, Some(42)]);
+    let result = array.cast_saturating(&DataType::UInt8)?;
+    assert_eq!(
+        vec![Some(0u8), Some(255), None, Some(42)],
+        result.u8()?.collect_values()
+    );
+
+    let array = DFUInt64Array::new_from_slice(&[u64::MAX, 7]);
+    let result = array.cast_saturating(&DataType::Int32)?;
+    assert_eq!(
+        vec![Some(i32::MAX), Some(7)],
+        result.i32()?.collect_values()
+    );
+
+    let array = DFFloat64Array::new_from_slice(&[-1e20, f64::NAN, 1.5]);
+    let result = array.cast_saturating(&DataType::Int8)?;
+    assert_eq!(
+        vec![Some(i8::MIN), None, Some(1)],
+        result.i8()?.collect_values()
+    );
+
+    let array = DFFloat64Array::new_from_slice(&[1e300, -1e300, f64::INFINITY, f64::NAN, 0.5]);
+    let result = array.cast_saturating(&DataType::Float32)?;
+    assert_eq!(
+        vec![
+            Some(f32::MAX),
+            Some(f32::MIN),
+            Some(f32::MAX),
+            None,
+            Some(0.5)
+        ],
+        result.f32()?.collect_values()
+    );
+
+    let result = array.cast_saturating(&DataType::Float64)?;
+    assert_eq!(
+        vec![Some(1e300), Some(-1e300), Some(f64::MAX), None, Some(0.5)],
+        result.f64()?.collect_values()
+    );
+
+    let result = array.cast_saturating(&DataType::Utf8);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Unsupported apply cast_saturating operation to Utf8"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt::Debug;
+
+use common_arrow::arrow::array::make_array;
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::ArrayRef;
+use common_arrow::arrow::compute;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::arrays::DataArray;
+use crate::DFBinaryArray;
+use crate::DFBooleanArray;
+use crate::DFListArray;
+use crate::DFNullArray;
+use crate::DFNumericType;
+use crate::DFStructArray;
+use crate::DFUtf8Array;
+
+pub trait ArrayFilter: Debug {
+    /// Keep only the rows where the mask is true and compact them into a new array.
+    /// Null entries of the mask are treated as false, the null bitmap of the
+    /// retained rows is preserved.
+    fn filter(&self, _mask: &DFBooleanArray) -> Result<Self>
+    where Self: std::marker::Sized {
+        Err(ErrorCode::BadDataValueType(format!(
+            "Unsupported apply filter operation for {:?}",
+            self,
+        )))
+    }
+}
+
+fn filter_array(array: &ArrayRef, mask: &DFBooleanArray) -> Result<ArrayRef> {
+    if mask.len() != array.len() {
+        return Err(ErrorCode::BadDataArrayLength(format!(
+            "cannot filter array, mask size not matched, got: {}, expect: {}",
+            mask.len(),
+            array.len(),
+        )));
+    }
+
+    if mask.null_count() > 0 {
+        let mask = compute::prep_null_mask_filter(mask.downcast_ref());
+        return filter_array(array, &DFBooleanArray::from_arrow_array(mask));
+    }
+
+    let filter = compute::build_filter(mask.downcast_ref())?;
+    Ok(make_array(filter(array.data())))
+}
+
+impl<T> ArrayFilter for DataArray<T>
+where T: DFNumericType
+{
+    fn filter(&self, mask: &DFBooleanArray) -> Result<Self> {
+        Ok(Self::from(filter_array(&self.array, mask)?))
+    }
+}
+
+impl ArrayFilter for DFUtf8Array {
+    fn filter(&self, mask: &DFBooleanArray) -> Result<Self> {
+        Ok(Self::from(filter_array(&self.array, mask)?))
+    }
+}
+
+impl ArrayFilter for DFBooleanArray {
+    fn filter(&self, mask: &DFBooleanArray) -> Result<Self> {
+        Ok(Self::from(filter_array(&self.array, mask)?))
+    }
+}
+
+impl ArrayFilter for DFBinaryArray {
+    fn filter(&self, mask: &DFBooleanArray) -> Result<Self> {
+        Ok(Self::from(filter_array(&self.array, mask)?))
+    }
+}
+
+impl ArrayFilter for DFListArray {
+    fn filter(&self, mask: &DFBooleanArray) -> Result<Self> {
+        Ok(Self::from(filter_array(&self.array, mask)?))
+    }
+}
+
+impl ArrayFilter for DFNullArray {}
+impl ArrayFilter for DFStructArray {}
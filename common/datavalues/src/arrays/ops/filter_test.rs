@@ -0,0 +1,87 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_exception::Result;
+
+use crate::arrays::builders::*;
+use crate::arrays::get_list_builder;
+use crate::arrays::ops::filter::ArrayFilter;
+use crate::prelude::*;
+use crate::DFBooleanArray;
+use crate::DFUInt16Array;
+use crate::DFUtf8Array;
+
+#[test]
+fn test_filter() -> Result<()> {
+    let mask = DFBooleanArray::new_from_opt_slice(&[Some(true), None, Some(false), Some(true)]);
+    let all_false = DFBooleanArray::new_from_slice(&[false, false, false, false]);
+
+    // Test DFUInt16Array
+    let df_uint16_array = DFUInt16Array::new_from_opt_slice(&[Some(1u16), Some(2), Some(3), None]);
+    let filtered = df_uint16_array.filter(&mask)?;
+    assert_eq!(filtered.collect_values(), vec![Some(1u16), None]);
+    let filtered = df_uint16_array.filter(&all_false)?;
+    assert_eq!(filtered.len(), 0);
+    assert_eq!(filtered.data_type(), DataType::UInt16);
+
+    // Test DFUtf8Array
+    let df_utf8_array = DFUtf8Array::new_from_opt_slice(&[Some("a"), Some("b"), None, Some("d")]);
+    let filtered = df_utf8_array.filter(&mask)?;
+    assert_eq!(filtered.collect_values(), vec![Some("a"), Some("d")]);
+    let filtered = df_utf8_array.filter(&all_false)?;
+    assert_eq!(filtered.len(), 0);
+    assert_eq!(filtered.data_type(), DataType::Utf8);
+
+    // Test DFBooleanArray
+    let df_bool_array =
+        DFBooleanArray::new_from_opt_slice(&[None, Some(true), Some(true), Some(false)]);
+    let filtered = df_bool_array.filter(&mask)?;
+    assert_eq!(filtered.collect_values(), vec![None, Some(false)]);
+    let filtered = df_bool_array.filter(&all_false)?;
+    assert_eq!(filtered.len(), 0);
+    assert_eq!(filtered.data_type(), DataType::Boolean);
+
+    // Test DFBinaryArray
+    let mut binary_builder = BinaryArrayBuilder::new(8);
+    binary_builder.append_value(&"12");
+    binary_builder.append_null();
+    binary_builder.append_value(&"c");
+    binary_builder.append_value(&"3");
+    let df_binary_array = binary_builder.finish();
+    let filtered = df_binary_array.filter(&mask)?;
+    assert_eq!(filtered.len(), 2);
+    assert_eq!(filtered.downcast_ref().value(0), b"12");
+    assert_eq!(filtered.downcast_ref().value(1), b"3");
+    let filtered = df_binary_array.filter(&all_false)?;
+    assert_eq!(filtered.len(), 0);
+    assert_eq!(filtered.data_type(), DataType::Binary);
+
+    // Test DFListArray
+    let mut builder = get_list_builder(&DataType::UInt16, 8, 4);
+    builder.append_series(&Series::new(vec![1_u16, 2]));
+    builder.append_series(&Series::new(vec![3_u16]));
+    builder.append_opt_series(None);
+    builder.append_opt_series(None);
+    let df_list = builder.finish();
+    let filtered = df_list.filter(&mask)?;
+    assert_eq!(filtered.len(), 2);
+    assert_eq!(filtered.null_count(), 1);
+    let values = filtered
+        .downcast_iter()
+        .map(|s| s.map(|s| s.u16().unwrap().collect_values()))
+        .collect::<Vec<_>>();
+    assert_eq!(values, vec![Some(vec![Some(1u16), Some(2)]), None]);
+    let filtered = df_list.filter(&all_false)?;
+    assert_eq!(filtered.len(), 0);
+    assert_eq!(filtered.data_type(), df_list.data_type());
+
+    // Mask length mismatch
+    let result = df_uint16_array.filter(&DFBooleanArray::new_from_slice(&[true]));
+    assert_eq!(
+        result.unwrap_err().message(),
+        "cannot filter array, mask size not matched, got: 1, expect: 4"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::DFInt64Array;
+use crate::DFTimestampMicrosecondArray;
+
+/// The unit of a plain integer interval value, such as the count in `INTERVAL 3 DAY`.
+/// Arrow's `IntervalUnit` only tells the YearMonth and DayTime storage layouts apart,
+/// so it can't name the unit a count is expressed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntervalTimeUnit {
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    Millisecond,
+    Microsecond,
+}
+
+impl IntervalTimeUnit {
+    /// The number of microseconds in one unit.
+    pub fn micros(&self) -> i64 {
+        match self {
+            IntervalTimeUnit::Week => 7 * 24 * 3_600_000_000,
+            IntervalTimeUnit::Day => 24 * 3_600_000_000,
+            IntervalTimeUnit::Hour => 3_600_000_000,
+            IntervalTimeUnit::Minute => 60_000_000,
+            IntervalTimeUnit::Second => 1_000_000,
+            IntervalTimeUnit::Millisecond => 1_000,
+            IntervalTimeUnit::Microsecond => 1,
+        }
+    }
+}
+
+impl DFInt64Array {
+    /// Convert interval values counted in `unit` into microseconds, null values stay null.
+    /// Returns a BadDataValueType error if a converted value overflows i64.
+    pub fn interval_to_micros(&self, unit: IntervalTimeUnit) -> Result<DFInt64Array> {
+        let micros = unit.micros();
+        self.downcast_iter()
+            .map(|value| match value {
+                Some(v) => match v.checked_mul(micros) {
+                    Some(v) => Ok(Some(v)),
+                    None => Err(ErrorCode::BadDataValueType(format!(
+                        "Interval {} {:?} overflows when converted to microseconds",
+                        v, unit
+                    ))),
+                },
+                None => Ok(None),
+            })
+            .collect()
+    }
+}
+
+impl DFTimestampMicrosecondArray {
+    /// Round every timestamp to the nearest multiple of `micros`, halfway values round up.
+    /// Null values stay null.
+    /// Returns a BadArguments error if `micros` is not positive, or a BadDataValueType
+    /// error if a rounded value overflows i64.
+    pub fn round_to_interval(&self, micros: i64) -> Result<DFTimestampMicrosecondArray> {
+        if micros <= 0 {
+            return Err(ErrorCode::BadArguments(format!(
+                "Rounding interval must be positive, got: {}",
+                micros
+            )));
+        }
+
+        self.downcast_iter()
+            .map(|value| match value {
+                Some(v) => {
+                    let rem = v.rem_euclid(micros);
+                    let floor = v - rem;
+                    match rem >= micros - rem {
+                        true => floor.checked_add(micros).map(Some).ok_or_else(|| {
+                            ErrorCode::BadDataValueType(format!(
+                                "Timestamp {} overflows when rounded to an interval of {} microseconds",
+                                v, micros
+                            ))
+                        }),
+                        false => Ok(Some(floor)),
+                    }
+                }
+                None => Ok(None),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_exception::Result;
+
+use crate::arrays::ops::interval::IntervalTimeUnit;
+use crate::prelude::*;
+use crate::DFInt64Array;
+use crate::DFTimestampMicrosecondArray;
+
+#[test]
+fn test_interval_to_micros() -> Result<()> {
+    let df_int64_array = DFInt64Array::new_from_opt_slice(&[Some(1i64), None, Some(-2)]);
+
+    let micros = df_int64_array.interval_to_micros(IntervalTimeUnit::Day)?;
+    assert_eq!(
+        vec![Some(86_400_000_000i64), None, Some(-172_800_000_000)],
+        micros.collect_values()
+    );
+
+    let micros = df_int64_array.interval_to_micros(IntervalTimeUnit::Hour)?;
+    assert_eq!(
+        vec![Some(3_600_000_000i64), None, Some(-7_200_000_000)],
+        micros.collect_values()
+    );
+
+    // i64::MAX microseconds is about 106751991 days
+    let df_int64_array = DFInt64Array::new_from_slice(&[1i64, 106_751_992]);
+    let result = df_int64_array.interval_to_micros(IntervalTimeUnit::Day);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Interval 106751992 Day overflows when converted to microseconds"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_timestamp_round_to_interval() -> Result<()> {
+    let minute = 60_000_000i64;
+    // 00:07:29, 00:07:30, NULL, 00:02:29.999999, 01:31:00
+    let df_timestamp_array = DFTimestampMicrosecondArray::new_from_opt_slice(&[
+        Some(7 * minute + 29_000_000),
+        Some(7 * minute + 30_000_000),
+        None,
+        Some(2 * minute + 29_999_999),
+        Some(91 * minute),
+    ]);
+
+    let rounded = df_timestamp_array.round_to_interval(5 * minute)?;
+    assert_eq!(
+        vec![
+            Some(5 * minute),
+            Some(10 * minute),
+            None,
+            Some(0),
+            Some(90 * minute)
+        ],
+        rounded.collect_values()
+    );
+
+    let rounded = df_timestamp_array.round_to_interval(60 * minute)?;
+    assert_eq!(
+        vec![Some(0), Some(0), None, Some(0), Some(120 * minute)],
+        rounded.collect_values()
+    );
+
+    // Timestamps before the epoch round towards the nearest boundary too
+    let df_timestamp_array = DFTimestampMicrosecondArray::new_from_slice(&[-3 * minute]);
+    let rounded = df_timestamp_array.round_to_interval(5 * minute)?;
+    assert_eq!(vec![Some(-5 * minute)], rounded.collect_values());
+
+    let result = df_timestamp_array.round_to_interval(0);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Rounding interval must be positive, got: 0"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,125 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::ArrayData;
+use common_arrow::arrow::array::BooleanArray;
+use common_arrow::arrow::array::ListArray;
+use common_arrow::arrow::array::StructArray;
+use common_arrow::arrow::array::UInt32Array;
+use common_arrow::arrow::buffer::Buffer;
+use common_arrow::arrow::compute;
+use common_arrow::arrow::compute::SortOptions;
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::DFListArray;
+use crate::DFStructArray;
+
+impl DFListArray {
+    /// Convert every fixed-length list into a struct whose fields are named by `field_names`.
+    /// The i-th element of each list becomes the i-th field of the struct.
+    /// Every non-null list must have exactly `field_names.len()` elements,
+    /// a null list yields a null struct row.
+    pub fn to_struct(&self, field_names: &[String]) -> Result<DFStructArray> {
+        if field_names.is_empty() {
+            return Err(ErrorCode::BadArguments(
+                "Cannot convert list to struct without any field names",
+            ));
+        }
+
+        let array = self.downcast_ref();
+        let offsets = array.value_offsets();
+        for row in 0..self.len() {
+            if !self.is_null(row) && array.value_length(row) as usize != field_names.len() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Cannot convert list to struct, expect {} elements in each list, but got {} at row {}",
+                    field_names.len(),
+                    array.value_length(row),
+                    row
+                )));
+            }
+        }
+
+        let values = array.values();
+        let mut fields = Vec::with_capacity(field_names.len());
+        let mut columns = Vec::with_capacity(field_names.len());
+        for (i, name) in field_names.iter().enumerate() {
+            let indices = (0..self.len())
+                .map(|row| match self.is_null(row) {
+                    true => None,
+                    false => Some(offsets[row] as u32 + i as u32),
+                })
+                .collect::<UInt32Array>();
+
+            let column = compute::take(values.as_ref(), &indices, None)?;
+            fields.push(ArrowField::new(name, column.data_type().clone(), true));
+            columns.push(column.data().clone());
+        }
+
+        // StructArray::from(Vec<(Field, ArrayRef)>) has no validity bitmap, the null
+        // lists are carried over as null struct rows through the struct's own bitmap.
+        let mut builder = ArrayData::builder(ArrowDataType::Struct(fields))
+            .len(self.len())
+            .child_data(columns);
+        if self.null_count() > 0 {
+            builder = builder.null_bit_buffer(validity_buffer(self));
+        }
+
+        Ok(DFStructArray::from_arrow_array(StructArray::from(
+            builder.build(),
+        )))
+    }
+
+    /// Sort the elements within each list independently, null lists stay null.
+    /// Null elements are placed according to `nulls_first`.
+    /// Returns an error if the list values are of a type arrow can't sort.
+    pub fn sort_each(&self, descending: bool, nulls_first: bool) -> Result<DFListArray> {
+        let array = self.downcast_ref();
+        let offsets = array.value_offsets();
+        let options = SortOptions {
+            descending,
+            nulls_first,
+        };
+
+        // Sort every list into indices of the list values, then take all the values at
+        // once, so any value type arrow can sort works without a typed list builder.
+        let mut indices: Vec<u32> = Vec::with_capacity(array.values().len());
+        let mut sorted_offsets: Vec<i32> = Vec::with_capacity(self.len() + 1);
+        sorted_offsets.push(0);
+        for row in 0..self.len() {
+            if !self.is_null(row) {
+                let sorted = compute::sort_to_indices(&array.value(row), Some(options), None)?;
+                let start = offsets[row] as u32;
+                indices.extend(sorted.values().iter().map(|index| start + index));
+            }
+            sorted_offsets.push(indices.len() as i32);
+        }
+
+        let indices = UInt32Array::from(indices);
+        let values = compute::take(array.values().as_ref(), &indices, None)?;
+
+        let mut builder = ArrayData::builder(array.data_type().clone())
+            .len(self.len())
+            .add_buffer(Buffer::from_slice_ref(&sorted_offsets))
+            .child_data(vec![values.data().clone()]);
+        if self.null_count() > 0 {
+            builder = builder.null_bit_buffer(validity_buffer(self));
+        }
+
+        Ok(DFListArray::from_arrow_array(ListArray::from(
+            builder.build(),
+        )))
+    }
+}
+
+/// A validity bitmap starting at the first row of `array`, whatever its offset.
+fn validity_buffer(array: &DFListArray) -> Buffer {
+    let validity = (0..array.len())
+        .map(|row| Some(!array.is_null(row)))
+        .collect::<BooleanArray>();
+    validity.values().clone()
+}
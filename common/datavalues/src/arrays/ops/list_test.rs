@@ -0,0 +1,131 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::ListBuilder;
+use common_arrow::arrow::array::TimestampMicrosecondArray;
+use common_arrow::arrow::array::TimestampMicrosecondBuilder;
+use common_exception::Result;
+
+use crate::arrays::get_list_builder;
+use crate::prelude::*;
+use crate::DFListArray;
+use crate::DFUInt16Array;
+
+#[test]
+fn test_list_to_struct() -> Result<()> {
+    let mut builder = get_list_builder(&DataType::UInt16, 6, 3);
+    builder.append_series(&Series::new(vec![1_u16, 2]));
+    builder.append_series(&Series::new(vec![3_u16, 4]));
+    builder.append_series(&Series::new(vec![5_u16, 6]));
+    let df_list = builder.finish();
+
+    let field_names = vec!["x".to_string(), "y".to_string()];
+    let df_struct = df_list.to_struct(&field_names)?;
+    let struct_array = df_struct.downcast_ref();
+    assert_eq!(struct_array.len(), 3);
+    assert_eq!(struct_array.num_columns(), 2);
+    assert_eq!(struct_array.column_names(), vec!["x", "y"]);
+
+    let x = DFUInt16Array::from(struct_array.column(0));
+    let y = DFUInt16Array::from(struct_array.column(1));
+    assert_eq!(x.collect_values(), vec![Some(1u16), Some(3), Some(5)]);
+    assert_eq!(y.collect_values(), vec![Some(2u16), Some(4), Some(6)]);
+
+    // A null list becomes a null struct row
+    let mut builder = get_list_builder(&DataType::UInt16, 4, 3);
+    builder.append_series(&Series::new(vec![1_u16, 2]));
+    builder.append_opt_series(None);
+    builder.append_series(&Series::new(vec![Some(3_u16), None]));
+    let df_list = builder.finish();
+
+    let df_struct = df_list.to_struct(&field_names)?;
+    let struct_array = df_struct.downcast_ref();
+    assert_eq!(struct_array.len(), 3);
+    assert_eq!(struct_array.null_count(), 1);
+    assert!(struct_array.is_null(1));
+    assert!(struct_array.is_valid(2));
+
+    let x = DFUInt16Array::from(struct_array.column(0));
+    let y = DFUInt16Array::from(struct_array.column(1));
+    assert_eq!(x.collect_values(), vec![Some(1u16), None, Some(3)]);
+    assert_eq!(y.collect_values(), vec![Some(2u16), None, None]);
+
+    // Ragged list
+    let mut builder = get_list_builder(&DataType::UInt16, 6, 2);
+    builder.append_series(&Series::new(vec![1_u16, 2]));
+    builder.append_series(&Series::new(vec![3_u16, 4, 5]));
+    let df_list = builder.finish();
+
+    let result = df_list.to_struct(&field_names);
+    assert!(result.is_err());
+    assert_eq!(
+        result.err().unwrap().message(),
+        "Cannot convert list to struct, expect 2 elements in each list, but got 3 at row 1"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_list_sort_each() -> Result<()> {
+    let mut builder = get_list_builder(&DataType::UInt16, 8, 4);
+    builder.append_series(&Series::new(vec![3_u16, 1, 2]));
+    builder.append_opt_series(None);
+    builder.append_series(&Series::new(vec![Some(5_u16), None, Some(4)]));
+    builder.append_series(&Series::new(Vec::<u16>::new()));
+    let df_list = builder.finish();
+
+    let values = |list: &DFListArray| {
+        list.downcast_iter()
+            .map(|s| s.map(|s| s.u16().unwrap().collect_values()))
+            .collect::<Vec<_>>()
+    };
+
+    let sorted = df_list.sort_each(false, false)?;
+    assert_eq!(values(&sorted), vec![
+        Some(vec![Some(1u16), Some(2), Some(3)]),
+        None,
+        Some(vec![Some(4u16), Some(5), None]),
+        Some(vec![]),
+    ]);
+
+    let sorted = df_list.sort_each(true, true)?;
+    assert_eq!(values(&sorted), vec![
+        Some(vec![Some(3u16), Some(2), Some(1)]),
+        None,
+        Some(vec![None, Some(5u16), Some(4)]),
+        Some(vec![]),
+    ]);
+
+    // Timestamps have no typed list builder, they are sorted all the same
+    let mut builder = ListBuilder::new(TimestampMicrosecondBuilder::new(5));
+    builder.values().append_slice(&[30, 10, 20])?;
+    builder.append(true)?;
+    builder.append(false)?;
+    builder.values().append_slice(&[2, 1])?;
+    builder.append(true)?;
+    let df_list = DFListArray::from_arrow_array(builder.finish());
+
+    let sorted = df_list.sort_each(false, false)?;
+    assert_eq!(sorted.data_type(), df_list.data_type());
+    assert!(sorted.is_null(1));
+    let list = sorted.downcast_ref();
+    let values = (0..list.len())
+        .map(|row| match list.is_null(row) {
+            true => None,
+            false => {
+                let values = list.value(row);
+                let values = values
+                    .as_any()
+                    .downcast_ref::<TimestampMicrosecondArray>()
+                    .unwrap();
+                Some(values.values().to_vec())
+            }
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(values, vec![Some(vec![10, 20, 30]), None, Some(vec![1, 2])]);
+
+    Ok(())
+}
@@ -4,16 +4,25 @@
 
 mod agg;
 mod apply;
+mod binary;
 mod boolean;
 mod cast;
 mod downcast;
 mod fill;
+mod filter;
 mod group_hash;
+mod interval;
+mod list;
+mod running;
+mod sample;
 mod scatter;
+mod scatter_into;
 mod take;
 mod take_random;
 mod take_single;
+mod tdigest;
 mod to_values;
+mod utf8;
 mod vec_hash;
 
 #[cfg(test)]
@@ -21,15 +30,31 @@ mod agg_test;
 #[cfg(test)]
 mod apply_test;
 #[cfg(test)]
+mod binary_test;
+#[cfg(test)]
 mod cast_test;
 #[cfg(test)]
 mod downcast_test;
 #[cfg(test)]
 mod fill_test;
 #[cfg(test)]
+mod filter_test;
+#[cfg(test)]
 mod group_hash_test;
 #[cfg(test)]
+mod interval_test;
+#[cfg(test)]
+mod list_test;
+#[cfg(test)]
+mod running_test;
+#[cfg(test)]
+mod sample_test;
+#[cfg(test)]
 mod scatter_test;
+#[cfg(test)]
+mod tdigest_test;
+#[cfg(test)]
+mod utf8_test;
 
 pub use agg::*;
 pub use apply::*;
@@ -37,10 +62,14 @@ pub use boolean::*;
 pub use cast::*;
 pub use downcast::*;
 pub use fill::*;
+pub use filter::*;
 pub use group_hash::GroupHash;
+pub use interval::*;
 pub use scatter::*;
+pub use scatter_into::*;
 pub use take::*;
 pub use take_random::*;
 pub use take_single::*;
+pub use tdigest::TDigest;
 pub use to_values::*;
 pub use vec_hash::*;
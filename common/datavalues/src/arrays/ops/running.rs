@@ -0,0 +1,71 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::cmp::Ordering;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::arrays::DataArray;
+use crate::DFNumericType;
+
+impl<T> DataArray<T>
+where T: DFNumericType
+{
+    /// Compute the running minimum or maximum along a sort order.
+    /// `order` is a permutation of the rows, the output at row `order[i]` is the
+    /// extreme of the rows `order[0..=i]`. Null values are ignored, so a row stays
+    /// null until the first non-null value in the order. NaN is ordered greater than
+    /// every other value, so it ends a running maximum and never becomes a running minimum
+    /// once another value has been seen.
+    /// Returns a BadArguments error if `order` is not a permutation of the rows.
+    pub fn running_extreme(&self, order: &[usize], want_max: bool) -> Result<Self> {
+        if order.len() != self.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Running order length must be equal to the array length, got: {}, expect: {}",
+                order.len(),
+                self.len()
+            )));
+        }
+
+        let array = self.downcast_ref();
+        let mut values: Vec<Option<T::Native>> = vec![None; self.len()];
+        let mut visited = vec![false; self.len()];
+        let mut extreme: Option<T::Native> = None;
+
+        for row in order {
+            let row = *row;
+            if row >= self.len() || visited[row] {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Running order must be a permutation of the rows, got row {} for an array of {} rows",
+                    row,
+                    self.len()
+                )));
+            }
+            visited[row] = true;
+
+            if !self.is_null(row) {
+                let value = array.value(row);
+                let replace = match extreme {
+                    Some(current) if want_max => nan_greatest_cmp(value, current).is_gt(),
+                    Some(current) => nan_greatest_cmp(value, current).is_lt(),
+                    None => true,
+                };
+                if replace {
+                    extreme = Some(value);
+                }
+            }
+            values[row] = extreme;
+        }
+
+        Ok(values.into_iter().collect())
+    }
+}
+
+/// Total order for the running extremes, placing NaN (the only value not equal to
+/// itself) above everything else.
+#[allow(clippy::eq_op)]
+fn nan_greatest_cmp<T: PartialOrd>(a: T, b: T) -> Ordering {
+    a.partial_cmp(&b).unwrap_or_else(|| (a != a).cmp(&(b != b)))
+}
@@ -0,0 +1,85 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_exception::Result;
+
+use crate::prelude::*;
+use crate::DFFloat64Array;
+use crate::DFUInt16Array;
+
+#[test]
+fn test_running_extreme() -> Result<()> {
+    let df_uint16_array =
+        DFUInt16Array::new_from_opt_slice(&[Some(3u16), None, Some(5), Some(1), Some(4)]);
+    let order = vec![3, 1, 0, 4, 2];
+
+    // Visited in order: 1, NULL, 3, 4, 5
+    let maxima = df_uint16_array.running_extreme(&order, true)?;
+    assert_eq!(
+        vec![Some(3u16), Some(1), Some(5), Some(1), Some(4)],
+        maxima.collect_values()
+    );
+
+    let minima = df_uint16_array.running_extreme(&order, false)?;
+    assert_eq!(
+        vec![Some(1u16), Some(1), Some(1), Some(1), Some(1)],
+        minima.collect_values()
+    );
+
+    // Rows before the first non-null value stay null
+    let order = vec![1, 2, 0, 3, 4];
+    let maxima = df_uint16_array.running_extreme(&order, true)?;
+    assert_eq!(
+        vec![Some(5u16), None, Some(5), Some(5), Some(5)],
+        maxima.collect_values()
+    );
+
+    let result = df_uint16_array.running_extreme(&[0, 1, 1, 2, 3], true);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Running order must be a permutation of the rows, got row 1 for an array of 5 rows"
+    );
+
+    let result = df_uint16_array.running_extreme(&[0, 1], true);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Running order length must be equal to the array length, got: 2, expect: 5"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_running_extreme_nan() -> Result<()> {
+    let df_float64_array = DFFloat64Array::new_from_slice(&[f64::NAN, 2.0, 1.0, 3.0]);
+    let values = |array: &DFFloat64Array| {
+        array
+            .collect_values()
+            .into_iter()
+            .map(|v| v.map(|v| v.to_string()))
+            .collect::<Vec<_>>()
+    };
+    let strings = |expect: &[f64]| {
+        expect
+            .iter()
+            .map(|v| Some(v.to_string()))
+            .collect::<Vec<_>>()
+    };
+
+    // A leading NaN is the maximum for good, but is replaced by the next minimum
+    let order = vec![0, 1, 2, 3];
+    let maxima = df_float64_array.running_extreme(&order, true)?;
+    assert_eq!(values(&maxima), strings(&[f64::NAN; 4]));
+    let minima = df_float64_array.running_extreme(&order, false)?;
+    assert_eq!(values(&minima), strings(&[f64::NAN, 2.0, 1.0, 1.0]));
+
+    // Visited in order: 2, 1, NaN, 3
+    let order = vec![1, 2, 0, 3];
+    let maxima = df_float64_array.running_extreme(&order, true)?;
+    assert_eq!(values(&maxima), strings(&[f64::NAN, 2.0, 2.0, f64::NAN]));
+    let minima = df_float64_array.running_extreme(&order, false)?;
+    assert_eq!(values(&minima), strings(&[1.0, 2.0, 1.0, 1.0]));
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::DFFloat64Array;
+
+impl DFFloat64Array {
+    /// Compute the A-Res sampling key `u ^ (1 / weight)` of every row, where this array
+    /// holds the row weights and `u` is a uniform random number in (0, 1) derived from
+    /// `seed` and the row number.
+    /// Taking the top-k rows by key yields a weighted random sample of k rows.
+    /// The keys are deterministic for a fixed seed; null weights yield null keys.
+    /// Returns a BadArguments error if a weight is not positive.
+    pub fn weighted_sample_keys(&self, seed: u64) -> Result<DFFloat64Array> {
+        self.downcast_iter()
+            .enumerate()
+            .map(|(row, weight)| match weight {
+                Some(weight) if weight > 0.0 => {
+                    let u = uniform(seed, row as u64);
+                    Ok(Some(u.powf(1.0 / weight)))
+                }
+                Some(weight) => Err(ErrorCode::BadArguments(format!(
+                    "Sample weight must be positive, got {} at row {}",
+                    weight, row
+                ))),
+                None => Ok(None),
+            })
+            .collect()
+    }
+}
+
+/// A uniform random number in the open interval (0, 1) for the given seed and row,
+/// using the splitmix64 mixer so that neighbouring rows are uncorrelated.
+fn uniform(seed: u64, row: u64) -> f64 {
+    let mut z = seed.wrapping_add(row.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+
+    // The top 53 bits, offset by half a step to exclude both 0 and 1.
+    ((z >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+}
@@ -0,0 +1,52 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_exception::Result;
+
+use crate::prelude::*;
+use crate::DFFloat64Array;
+
+#[test]
+fn test_weighted_sample_keys() -> Result<()> {
+    let weights = DFFloat64Array::new_from_opt_slice(&[Some(1.0), None, Some(2.0), Some(0.5)]);
+
+    // Deterministic for a fixed seed
+    let keys = weights.weighted_sample_keys(42)?;
+    let again = weights.weighted_sample_keys(42)?;
+    assert_eq!(keys.collect_values(), again.collect_values());
+    assert_eq!(keys.null_count(), 1);
+    assert!(keys.is_null(1));
+    for key in keys.downcast_iter().flatten() {
+        assert!(key > 0.0 && key <= 1.0);
+    }
+
+    let other = weights.weighted_sample_keys(43)?;
+    assert_ne!(keys.collect_values(), other.collect_values());
+
+    // Higher weight rows tend to produce larger keys
+    let weights = (0..1000)
+        .map(|row| if row % 2 == 0 { 1.0 } else { 100.0 })
+        .collect::<Vec<f64>>();
+    let weights = DFFloat64Array::new_from_slice(&weights);
+    let keys = weights.weighted_sample_keys(7)?;
+    let keys = keys.collect_values();
+    let mean = |parity: usize| {
+        keys.iter()
+            .enumerate()
+            .filter(|(row, _)| row % 2 == parity)
+            .map(|(_, key)| key.unwrap())
+            .sum::<f64>()
+            / 500.0
+    };
+    assert!(mean(1) > mean(0) + 0.3);
+
+    let weights = DFFloat64Array::new_from_slice(&[1.0, 0.0]);
+    let result = weights.weighted_sample_keys(0);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Sample weight must be positive, got 0 at row 1"
+    );
+
+    Ok(())
+}
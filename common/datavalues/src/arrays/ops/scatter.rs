@@ -2,8 +2,12 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
+use std::collections::hash_map::DefaultHasher;
 use std::fmt::Debug;
 
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::UInt64Array;
+use common_arrow::arrow::compute;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
@@ -11,25 +15,35 @@ use crate::arrays::get_list_builder;
 use crate::arrays::BinaryArrayBuilder;
 use crate::arrays::BooleanArrayBuilder;
 use crate::arrays::DataArray;
+use crate::arrays::ListBuilderTrait;
 use crate::arrays::PrimitiveArrayBuilder;
 use crate::arrays::Utf8ArrayBuilder;
+use crate::arrays::VecHash;
 use crate::prelude::*;
-use crate::utils::get_iter_capacity;
 use crate::*;
 
 pub trait ArrayScatter: Debug {
-    /// # Safety
-    /// Note this doesn't do any bound checking, for performance reason.
-
     /// scatter_unchecked() partitions the input array into multiple arrays.
     /// _indices: an iterateor of vector whose length is the same as the array.
     /// The element of _indices indicates which group the corresponding row
     /// in the input array belongs to.
     /// _scattered_size: the number of partitions
+    /// Indices are u64 and rows are addressed by usize all the way down,
+    /// so neither the array length nor the partition number is limited to 32 bits.
     ///
-    /// Example: if the input array has five rows [1, 2, 3, 4] and
+    /// Example: if the input array has four rows [1, 2, 3, 4] and
     /// _indices = [0, 1, 0, 1] and _scatter_size = 2,
-    /// then the output would be a vector of two arrays: [1, 4] and [2, 3].
+    /// then the output would be a vector of two arrays: [1, 3] and [2, 4].
+    ///
+    /// An empty input array yields _scattered_size empty arrays of the same data type.
+    /// A _scattered_size of 0 is only valid for an empty input array and yields no arrays.
+    ///
+    /// # Safety
+    /// Note this doesn't do any bound checking, for performance reason.
+    /// The caller must guarantee that _indices yields exactly one item per row
+    /// of the array and that every item is less than _scattered_size.
+    /// A length mismatch is only caught by a debug assertion; use
+    /// `DataArray::scatter` for a checked variant.
     unsafe fn scatter_unchecked(
         &self,
         _indices: &mut dyn Iterator<Item = u64>,
@@ -43,6 +57,351 @@ pub trait ArrayScatter: Debug {
             self,
         )))
     }
+
+    /// partition_sizes() counts how many rows scatter_unchecked() would put into
+    /// each of the num_chunks partitions, without building the partitions.
+    /// Returns a BadArguments error if any index is out of the range [0, num_chunks).
+    fn partition_sizes(indices: &[u64], num_chunks: usize) -> Result<Vec<usize>>
+    where Self: std::marker::Sized {
+        let mut sizes = vec![0; num_chunks];
+        for index in indices {
+            if *index >= num_chunks as u64 {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Scatter index {} is out of range, the scattered size is {}",
+                    index, num_chunks
+                )));
+            }
+            sizes[*index as usize] += 1;
+        }
+        Ok(sizes)
+    }
+
+    /// gather_chunks() is the inverse of scatter_unchecked().
+    /// Row i of the output is the next unconsumed row of chunks[indices[i]],
+    /// so gathering the scattered chunks with the same indices restores the
+    /// original array.
+    /// Returns a BadArguments error if an index is out of the range [0, chunks.len()),
+    /// if a chunk runs out of rows, or if some rows of a chunk are left unconsumed.
+    fn gather_chunks(_chunks: &[Self], _indices: &[u64]) -> Result<Self>
+    where Self: std::marker::Sized {
+        Err(ErrorCode::BadDataValueType(format!(
+            "Unsupported apply gather_chunks operation for {}",
+            std::any::type_name::<Self>(),
+        )))
+    }
+}
+
+impl<T> DataArray<T>
+where DataArray<T>: ArrayScatter
+{
+    /// Checked variant of scatter_unchecked().
+    /// Returns a BadArguments error if the number of indices differs from the
+    /// number of rows, or if any index is out of the range [0, scattered_size).
+    /// Returns a BadArguments error if scattered_size is 0, even for an empty array.
+    /// An empty array yields scattered_size empty chunks.
+    /// Every produced chunk is checked to carry the data type of the source array.
+    pub fn scatter(&self, indices: &[u64], scattered_size: usize) -> Result<Vec<Self>> {
+        if scattered_size == 0 {
+            return Err(ErrorCode::BadArguments(
+                "Scatter requires at least one chunk",
+            ));
+        }
+
+        if indices.len() != self.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Scatter indices length must be equal to the array length, got: {}, expect: {}",
+                indices.len(),
+                self.len()
+            )));
+        }
+
+        if let Some(index) = indices
+            .iter()
+            .find(|index| **index >= scattered_size as u64)
+        {
+            return Err(ErrorCode::BadArguments(format!(
+                "Scatter index {} is out of range, the scattered size is {}",
+                index, scattered_size
+            )));
+        }
+
+        let chunks =
+            unsafe { self.scatter_unchecked(&mut indices.iter().copied(), scattered_size)? };
+        assert_chunk_types(self, &chunks)?;
+        Ok(chunks)
+    }
+
+    /// Round-robin partition the array into `num_chunks` chunks for balanced load,
+    /// row i goes to the chunk `i % num_chunks`. Chunk sizes differ by at most one
+    /// and rows keep their relative order within each chunk.
+    pub fn round_robin(&self, num_chunks: usize) -> Result<Vec<Self>> {
+        if num_chunks == 0 {
+            return Err(ErrorCode::BadArguments(
+                "Scatter requires at least one chunk",
+            ));
+        }
+
+        let indices = (0..self.len())
+            .map(|row| (row % num_chunks) as u64)
+            .collect::<Vec<_>>();
+        self.scatter(&indices, num_chunks)
+    }
+
+    /// Scatter by chunk ids that are already materialized as an array, e.g. the
+    /// output of a hash expression, reading them in place instead of through an iterator.
+    /// Returns a BadArguments error if the number of ids differs from the number of rows,
+    /// if any id is null, or if any id is out of the range [0, num_chunks).
+    pub fn scatter_by_ids(
+        &self,
+        chunk_ids: &DFUInt32Array,
+        num_chunks: usize,
+    ) -> Result<Vec<Self>> {
+        if num_chunks == 0 {
+            return Err(ErrorCode::BadArguments(
+                "Scatter requires at least one chunk",
+            ));
+        }
+
+        if chunk_ids.len() != self.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Scatter chunk ids length must be equal to the array length, got: {}, expect: {}",
+                chunk_ids.len(),
+                self.len()
+            )));
+        }
+
+        if chunk_ids.null_count() > 0 {
+            let row = (0..chunk_ids.len())
+                .find(|row| chunk_ids.is_null(*row))
+                .unwrap_or_default();
+            return Err(ErrorCode::BadArguments(format!(
+                "Scatter chunk id at row {} is null",
+                row
+            )));
+        }
+
+        let ids = chunk_ids.downcast_ref().values();
+        if let Some(id) = ids.iter().find(|id| **id as usize >= num_chunks) {
+            return Err(ErrorCode::BadArguments(format!(
+                "Scatter index {} is out of range, the scattered size is {}",
+                id, num_chunks
+            )));
+        }
+
+        let chunks =
+            unsafe { self.scatter_unchecked(&mut ids.iter().map(|id| *id as u64), num_chunks)? };
+        assert_chunk_types(self, &chunks)?;
+        Ok(chunks)
+    }
+}
+
+/// Checks that every chunk carries the same data type as the source array,
+/// including the inner type of lists.
+/// Returns a LogicalError naming the first chunk whose data type differs.
+pub fn assert_chunk_types<T>(source: &DataArray<T>, chunks: &[DataArray<T>]) -> Result<()> {
+    let data_type = source.data_type();
+    for (index, chunk) in chunks.iter().enumerate() {
+        let chunk_type = chunk.data_type();
+        if chunk_type != data_type {
+            return Err(ErrorCode::LogicalError(format!(
+                "Scattered chunk {} has data type {:?}, but the source array has {:?}",
+                index, chunk_type, data_type
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Checks that every caller-provided list builder appends values of the same data
+/// type as the list values of the source array, as scatter_into() requires.
+/// Returns a BadArguments error naming the first builder whose value type differs.
+pub fn assert_list_builder_types(
+    source: &DFListArray,
+    builders: &[Box<dyn ListBuilderTrait>],
+) -> Result<()> {
+    let data_type = source.sub_data_type();
+    for (index, builder) in builders.iter().enumerate() {
+        let builder_type = builder.data_type();
+        if builder_type != data_type {
+            return Err(ErrorCode::BadArguments(format!(
+                "Scatter builder {} builds lists of {:?}, but the array has lists of {:?}",
+                index, builder_type, data_type
+            )));
+        }
+    }
+    Ok(())
+}
+
+impl<T> DataArray<T>
+where DataArray<T>: ArrayScatter + VecHash
+{
+    /// Hash partition the array into `scattered_size` chunks.
+    /// Each row goes to the chunk `hash(value) % scattered_size`, so equal values
+    /// always land in the same chunk. Null values always go to chunk 0.
+    pub fn hash_partition(&self, scattered_size: usize) -> Result<Vec<Self>> {
+        if scattered_size == 0 {
+            return Err(ErrorCode::BadArguments(
+                "Hash partition requires at least one chunk",
+            ));
+        }
+
+        // SipHasher starts from the same keys every time, unlike AhashHasher whose
+        // clone_initial() draws a new random state.
+        let hashes = self.vec_hash(DFHasher::SipHasher(DefaultHasher::new()))?;
+        let indices = hashes
+            .downcast_iter()
+            .map(|hash| match hash {
+                Some(hash) => hash % scattered_size as u64,
+                None => 0,
+            })
+            .collect::<Vec<_>>();
+
+        self.scatter(&indices, scattered_size)
+    }
+}
+
+/// Pairs each row of an array with the partition index it is scattered to.
+/// In debug builds, asserts that the indices iterator yields exactly one index per row.
+struct ScatterIndices<'a> {
+    indices: &'a mut dyn Iterator<Item = u64>,
+    rows: usize,
+    row: usize,
+}
+
+impl<'a> ScatterIndices<'a> {
+    fn new(indices: &'a mut dyn Iterator<Item = u64>, rows: usize) -> Self {
+        Self {
+            indices,
+            rows,
+            row: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for ScatterIndices<'a> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row == self.rows {
+            debug_assert!(
+                self.indices.next().is_none(),
+                "scatter_unchecked: indices yield more items than the {} rows of the array",
+                self.rows
+            );
+            return None;
+        }
+
+        match self.indices.next() {
+            Some(index) => {
+                let row = self.row;
+                self.row += 1;
+                Some((index as usize, row))
+            }
+            None => {
+                debug_assert_eq!(
+                    self.row, self.rows,
+                    "scatter_unchecked: indices yield fewer items than the rows of the array"
+                );
+                None
+            }
+        }
+    }
+}
+
+/// Tracks the next unconsumed row of every chunk for gather_chunks().
+struct GatherRows<'a, T> {
+    chunks: &'a [DataArray<T>],
+    cursors: Vec<usize>,
+}
+
+impl<'a, T> GatherRows<'a, T> {
+    fn new(chunks: &'a [DataArray<T>]) -> Self {
+        Self {
+            chunks,
+            cursors: vec![0; chunks.len()],
+        }
+    }
+
+    /// Returns the chunk that `index` refers to and the row to read from it.
+    fn next_row(&mut self, index: u64) -> Result<(&'a DataArray<T>, usize)> {
+        let index = index as usize;
+        if index >= self.chunks.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Gather index {} is out of range, the number of chunks is {}",
+                index,
+                self.chunks.len()
+            )));
+        }
+
+        let chunks = self.chunks;
+        let chunk = &chunks[index];
+        let row = self.cursors[index];
+        if row >= chunk.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Gather chunk {} has only {} rows, but more are referenced by indices",
+                index,
+                chunk.len()
+            )));
+        }
+
+        self.cursors[index] += 1;
+        Ok((chunk, row))
+    }
+
+    /// Checks that every row of every chunk has been gathered.
+    fn finish(&self) -> Result<()> {
+        for (index, chunk) in self.chunks.iter().enumerate() {
+            if self.cursors[index] != chunk.len() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Gather chunk {} has {} rows, but only {} are referenced by indices",
+                    index,
+                    chunk.len(),
+                    self.cursors[index]
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether get_list_builder() can build lists of the given value type.
+fn list_builder_supports(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Utf8
+            | DataType::Boolean
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64
+            | DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::Float32
+            | DataType::Float64
+            | DataType::Date32
+            | DataType::Date64
+    )
+}
+
+/// Counts the rows and the value length routed to every chunk, for sizing the
+/// builders of variable-length arrays. `value_length` is called for non-null rows only.
+fn routed_capacities(
+    routes: &[(usize, usize)],
+    scattered_size: usize,
+    value_length: impl Fn(usize) -> usize,
+    is_null: impl Fn(usize) -> bool,
+) -> Vec<(usize, usize)> {
+    let mut capacities = vec![(0usize, 0usize); scattered_size];
+    for (index, row) in routes.iter() {
+        let capacity = &mut capacities[*index];
+        capacity.0 += 1;
+        if !is_null(*row) {
+            capacity.1 = capacity.1.saturating_add(value_length(*row));
+        }
+    }
+    capacities
 }
 
 impl<T> ArrayScatter for DataArray<T>
@@ -65,16 +424,16 @@ where T: DFNumericType
 
         match self.null_count() {
             0 => {
-                indices.zip(0..self.len()).for_each(|(index, row)| {
-                    builders[index as usize].append_value(array.value(row));
+                ScatterIndices::new(indices, self.len()).for_each(|(index, row)| {
+                    builders[index].append_value(array.value(row));
                 });
             }
             _ => {
-                indices.zip(0..self.len()).for_each(|(index, row)| {
+                ScatterIndices::new(indices, self.len()).for_each(|(index, row)| {
                     if self.is_null(row) {
-                        builders[index as usize].append_null();
+                        builders[index].append_null();
                     } else {
-                        builders[index as usize].append_value(array.value(row));
+                        builders[index].append_value(array.value(row));
                     }
                 });
             }
@@ -85,6 +444,24 @@ where T: DFNumericType
             .map(|builder| builder.finish())
             .collect())
     }
+
+    fn gather_chunks(chunks: &[Self], indices: &[u64]) -> Result<Self>
+    where Self: std::marker::Sized {
+        let mut builder = PrimitiveArrayBuilder::<T>::new(indices.len());
+        let mut gather = GatherRows::new(chunks);
+
+        for index in indices {
+            let (chunk, row) = gather.next_row(*index)?;
+            if chunk.is_null(row) {
+                builder.append_null();
+            } else {
+                builder.append_value(chunk.downcast_ref().value(row));
+            }
+        }
+
+        gather.finish()?;
+        Ok(builder.finish())
+    }
 }
 
 impl ArrayScatter for DFUtf8Array {
@@ -97,27 +474,31 @@ impl ArrayScatter for DFUtf8Array {
         Self: std::marker::Sized,
     {
         let array = self.downcast_ref();
-        let mut builders = Vec::with_capacity(scattered_size);
+        let routes = ScatterIndices::new(indices, self.len()).collect::<Vec<_>>();
+        let capacities = routed_capacities(
+            &routes,
+            scattered_size,
+            |row| array.value_length(row) as usize,
+            |row| self.is_null(row),
+        );
 
-        for _i in 0..scattered_size {
-            builders.push(Utf8ArrayBuilder::new(
-                self.len(),
-                self.get_array_memory_size(),
-            ));
-        }
+        let mut builders = capacities
+            .into_iter()
+            .map(|(rows, bytes)| Utf8ArrayBuilder::new(rows, bytes))
+            .collect::<Vec<_>>();
 
         match self.null_count() {
             0 => {
-                indices.zip(0..self.len()).for_each(|(index, row)| {
-                    builders[index as usize].append_value(array.value(row));
+                routes.into_iter().for_each(|(index, row)| {
+                    builders[index].append_value(array.value(row));
                 });
             }
             _ => {
-                indices.zip(0..self.len()).for_each(|(index, row)| {
+                routes.into_iter().for_each(|(index, row)| {
                     if self.is_null(row) {
-                        builders[index as usize].append_null();
+                        builders[index].append_null();
                     } else {
-                        builders[index as usize].append_value(array.value(row));
+                        builders[index].append_value(array.value(row));
                     }
                 });
             }
@@ -128,6 +509,28 @@ impl ArrayScatter for DFUtf8Array {
             .map(|builder| builder.finish())
             .collect())
     }
+
+    fn gather_chunks(chunks: &[Self], indices: &[u64]) -> Result<Self>
+    where Self: std::marker::Sized {
+        let bytes_capacity = chunks
+            .iter()
+            .map(|chunk| chunk.downcast_ref().value_data().len())
+            .sum();
+        let mut builder = Utf8ArrayBuilder::new(indices.len(), bytes_capacity);
+        let mut gather = GatherRows::new(chunks);
+
+        for index in indices {
+            let (chunk, row) = gather.next_row(*index)?;
+            if chunk.is_null(row) {
+                builder.append_null();
+            } else {
+                builder.append_value(chunk.downcast_ref().value(row));
+            }
+        }
+
+        gather.finish()?;
+        Ok(builder.finish())
+    }
 }
 
 impl ArrayScatter for DFBooleanArray {
@@ -148,16 +551,16 @@ impl ArrayScatter for DFBooleanArray {
 
         match self.null_count() {
             0 => {
-                indices.zip(0..self.len()).for_each(|(index, row)| {
-                    builders[index as usize].append_value(array.value(row));
+                ScatterIndices::new(indices, self.len()).for_each(|(index, row)| {
+                    builders[index].append_value(array.value(row));
                 });
             }
             _ => {
-                indices.zip(0..self.len()).for_each(|(index, row)| {
+                ScatterIndices::new(indices, self.len()).for_each(|(index, row)| {
                     if self.is_null(row) {
-                        builders[index as usize].append_null();
+                        builders[index].append_null();
                     } else {
-                        builders[index as usize].append_value(array.value(row));
+                        builders[index].append_value(array.value(row));
                     }
                 });
             }
@@ -168,6 +571,24 @@ impl ArrayScatter for DFBooleanArray {
             .map(|builder| builder.finish())
             .collect())
     }
+
+    fn gather_chunks(chunks: &[Self], indices: &[u64]) -> Result<Self>
+    where Self: std::marker::Sized {
+        let mut builder = BooleanArrayBuilder::new(indices.len());
+        let mut gather = GatherRows::new(chunks);
+
+        for index in indices {
+            let (chunk, row) = gather.next_row(*index)?;
+            if chunk.is_null(row) {
+                builder.append_null();
+            } else {
+                builder.append_value(chunk.downcast_ref().value(row));
+            }
+        }
+
+        gather.finish()?;
+        Ok(builder.finish())
+    }
 }
 
 impl ArrayScatter for DFListArray {
@@ -179,29 +600,57 @@ impl ArrayScatter for DFListArray {
     where
         Self: std::marker::Sized,
     {
-        let mut builders = Vec::with_capacity(scattered_size);
+        let routes = ScatterIndices::new(indices, self.len()).collect::<Vec<_>>();
 
-        let capacity = get_iter_capacity(&indices);
-        for _i in 0..scattered_size {
-            let builder = get_list_builder(&self.sub_data_type(), capacity * 5, capacity);
+        // Lists whose values get_list_builder() can't build (nested lists, binary,
+        // timestamps...) are scattered by taking the routed rows of every chunk instead.
+        if !list_builder_supports(&self.sub_data_type()) {
+            let mut rows: Vec<Vec<u64>> = vec![Vec::new(); scattered_size];
+            for (index, row) in routes {
+                rows[index].push(row as u64);
+            }
 
-            builders.push(builder);
+            return rows
+                .into_iter()
+                .map(|rows| {
+                    let indices = UInt64Array::from(rows);
+                    let array = compute::take(self.array.as_ref(), &indices, None)?;
+                    Ok(array.into())
+                })
+                .collect();
         }
 
+        // Size every chunk builder by the lists and values actually routed to it.
+        let array = self.downcast_ref();
+        let capacities = routed_capacities(
+            &routes,
+            scattered_size,
+            |row| array.value_length(row) as usize,
+            |row| self.is_null(row),
+        );
+
+        let sub_data_type = self.sub_data_type();
+        let mut builders = capacities
+            .into_iter()
+            .map(|(lists, values)| get_list_builder(&sub_data_type, values, lists))
+            .collect::<Vec<_>>();
+
         let taker = self.take_rand();
 
         match self.null_count() {
             0 => {
-                indices.zip(0..self.len()).for_each(|(index, row)| {
-                    builders[index as usize].append_series(&taker.get_unchecked(row));
+                routes.into_iter().for_each(|(index, row)| {
+                    builders[index].append_series(&taker.get_unchecked(row));
                 });
             }
             _ => {
-                indices.zip(0..self.len()).for_each(|(index, row)| {
+                routes.into_iter().for_each(|(index, row)| {
                     if self.is_null(row) {
-                        builders[index as usize].append_null();
+                        // ListBuilderTrait::append_null() appends a list holding a single null
+                        // element, a null list row must clear the validity bit of the list itself.
+                        builders[index].append_opt_series(None);
                     } else {
-                        builders[index as usize].append_series(&taker.get_unchecked(row));
+                        builders[index].append_series(&taker.get_unchecked(row));
                     }
                 });
             }
@@ -212,6 +661,38 @@ impl ArrayScatter for DFListArray {
             .map(|builder| builder.finish())
             .collect())
     }
+
+    fn gather_chunks(chunks: &[Self], indices: &[u64]) -> Result<Self>
+    where Self: std::marker::Sized {
+        if chunks.is_empty() {
+            return Err(ErrorCode::BadArguments(
+                "Cannot gather list chunks without any chunk",
+            ));
+        }
+
+        // get_list_builder() can't build every inner type (nested lists, binary, timestamps...),
+        // so the chunks are concatenated and the gathered order is taken from the result.
+        let mut offsets = Vec::with_capacity(chunks.len());
+        let mut offset = 0;
+        for chunk in chunks {
+            offsets.push(offset);
+            offset += chunk.len();
+        }
+
+        let mut take_indices = Vec::with_capacity(indices.len());
+        let mut gather = GatherRows::new(chunks);
+        for index in indices {
+            let (_, row) = gather.next_row(*index)?;
+            take_indices.push((offsets[*index as usize] + row) as u64);
+        }
+        gather.finish()?;
+
+        let arrays: Vec<&dyn Array> = chunks.iter().map(|chunk| chunk.array.as_ref()).collect();
+        let array = compute::concat(&arrays)?;
+        let take_indices = UInt64Array::from(take_indices);
+        let array = compute::take(array.as_ref(), &take_indices, None)?;
+        Ok(array.into())
+    }
 }
 
 impl ArrayScatter for DFBinaryArray {
@@ -223,19 +704,25 @@ impl ArrayScatter for DFBinaryArray {
     where
         Self: std::marker::Sized,
     {
-        let mut builders = Vec::with_capacity(scattered_size);
-        let guess_scattered_len = ((self.len() as f64) * 1.1 / (scattered_size as f64)) as usize;
-        for _i in 0..scattered_size {
-            let builder = BinaryArrayBuilder::new(guess_scattered_len);
-            builders.push(builder);
-        }
-
         let binary_data = self.downcast_ref();
-        for (i, index) in indices.enumerate() {
-            if !self.is_null(i as usize) {
-                builders[index as usize].append_value(binary_data.value(i as usize));
+        let routes = ScatterIndices::new(indices, self.len()).collect::<Vec<_>>();
+        let capacities = routed_capacities(
+            &routes,
+            scattered_size,
+            |row| binary_data.value_length(row) as usize,
+            |row| self.is_null(row),
+        );
+
+        let mut builders = capacities
+            .into_iter()
+            .map(|(rows, bytes)| BinaryArrayBuilder::with_capacity(rows, bytes))
+            .collect::<Vec<_>>();
+
+        for (index, row) in routes {
+            if !self.is_null(row) {
+                builders[index].append_value(binary_data.value(row));
             } else {
-                builders[index as usize].append_null();
+                builders[index].append_null();
             }
         }
 
@@ -244,6 +731,28 @@ impl ArrayScatter for DFBinaryArray {
             .map(|builder| builder.finish())
             .collect())
     }
+
+    fn gather_chunks(chunks: &[Self], indices: &[u64]) -> Result<Self>
+    where Self: std::marker::Sized {
+        let bytes_capacity = chunks
+            .iter()
+            .map(|chunk| chunk.downcast_ref().value_data().len())
+            .sum();
+        let mut builder = BinaryArrayBuilder::with_capacity(indices.len(), bytes_capacity);
+        let mut gather = GatherRows::new(chunks);
+
+        for index in indices {
+            let (chunk, row) = gather.next_row(*index)?;
+            if chunk.is_null(row) {
+                builder.append_null();
+            } else {
+                builder.append_value(chunk.downcast_ref().value(row));
+            }
+        }
+
+        gather.finish()?;
+        Ok(builder.finish())
+    }
 }
 
 impl ArrayScatter for DFNullArray {}
@@ -0,0 +1,161 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt::Debug;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::arrays::assert_list_builder_types;
+use crate::arrays::ArrayBuilder;
+use crate::arrays::BinaryArrayBuilder;
+use crate::arrays::BooleanArrayBuilder;
+use crate::arrays::DataArray;
+use crate::arrays::IntoTakeRandom;
+use crate::arrays::ListBuilderTrait;
+use crate::arrays::PrimitiveArrayBuilder;
+use crate::arrays::TakeRandom;
+use crate::arrays::Utf8ArrayBuilder;
+use crate::*;
+
+pub trait ArrayScatterInto: Debug {
+    type Builder;
+
+    /// scatter_into() appends the rows of the array into caller-provided builders,
+    /// one builder per partition, so the same builders can be reused across many
+    /// input arrays and finished once at the end.
+    /// The element of indices indicates which builder the corresponding row is appended to.
+    /// The builder type is tied to the array type, so builders of a different type
+    /// are rejected at compile time instead of failing at runtime.
+    ///
+    /// Returns a BadArguments error if the number of indices differs from the
+    /// number of rows, or if any index is out of the range [0, builders.len()).
+    /// The indices are checked before any row is appended, so the builders are
+    /// left untouched on error.
+    fn scatter_into(&self, indices: &[u64], builders: &mut [Self::Builder]) -> Result<()>;
+}
+
+/// Validates the indices of scatter_into() without copying them.
+fn check_indices(indices: &[u64], rows: usize, scattered_size: usize) -> Result<()> {
+    if indices.len() != rows {
+        return Err(ErrorCode::BadArguments(format!(
+            "Scatter indices length must be equal to the array length, got: {}, expect: {}",
+            indices.len(),
+            rows
+        )));
+    }
+
+    if let Some(index) = indices
+        .iter()
+        .find(|index| **index as usize >= scattered_size)
+    {
+        return Err(ErrorCode::BadArguments(format!(
+            "Scatter index {} is out of range, the number of builders is {}",
+            index, scattered_size
+        )));
+    }
+
+    Ok(())
+}
+
+impl<T> ArrayScatterInto for DataArray<T>
+where T: DFNumericType
+{
+    type Builder = PrimitiveArrayBuilder<T>;
+
+    fn scatter_into(&self, indices: &[u64], builders: &mut [Self::Builder]) -> Result<()> {
+        check_indices(indices, self.len(), builders.len())?;
+        let array = self.downcast_ref();
+
+        for (row, index) in indices.iter().enumerate() {
+            let index = *index as usize;
+            if self.is_null(row) {
+                builders[index].append_null();
+            } else {
+                builders[index].append_value(array.value(row));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ArrayScatterInto for DFUtf8Array {
+    type Builder = Utf8ArrayBuilder;
+
+    fn scatter_into(&self, indices: &[u64], builders: &mut [Self::Builder]) -> Result<()> {
+        check_indices(indices, self.len(), builders.len())?;
+        let array = self.downcast_ref();
+
+        for (row, index) in indices.iter().enumerate() {
+            let index = *index as usize;
+            if self.is_null(row) {
+                builders[index].append_null();
+            } else {
+                builders[index].append_value(array.value(row));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ArrayScatterInto for DFBooleanArray {
+    type Builder = BooleanArrayBuilder;
+
+    fn scatter_into(&self, indices: &[u64], builders: &mut [Self::Builder]) -> Result<()> {
+        check_indices(indices, self.len(), builders.len())?;
+        let array = self.downcast_ref();
+
+        for (row, index) in indices.iter().enumerate() {
+            let index = *index as usize;
+            if self.is_null(row) {
+                builders[index].append_null();
+            } else {
+                builders[index].append_value(array.value(row));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ArrayScatterInto for DFBinaryArray {
+    type Builder = BinaryArrayBuilder;
+
+    fn scatter_into(&self, indices: &[u64], builders: &mut [Self::Builder]) -> Result<()> {
+        check_indices(indices, self.len(), builders.len())?;
+        let array = self.downcast_ref();
+
+        for (row, index) in indices.iter().enumerate() {
+            let index = *index as usize;
+            if self.is_null(row) {
+                builders[index].append_null();
+            } else {
+                builders[index].append_value(array.value(row));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ArrayScatterInto for DFListArray {
+    /// Builders created by get_list_builder() for the value type of the array,
+    /// builders of any other value type are rejected with a BadArguments error.
+    type Builder = Box<dyn ListBuilderTrait>;
+
+    fn scatter_into(&self, indices: &[u64], builders: &mut [Self::Builder]) -> Result<()> {
+        check_indices(indices, self.len(), builders.len())?;
+
+        // The list builders are type-erased, check their value type before appending
+        // anything, otherwise the values would be reinterpreted as the wrong type.
+        assert_list_builder_types(self, builders)?;
+
+        let taker = self.take_rand();
+
+        for (row, index) in indices.iter().enumerate() {
+            // A null list row must be appended as None, append_null() appends a list
+            // holding a single null element.
+            builders[*index as usize].append_opt_series(taker.get(row).as_ref());
+        }
+        Ok(())
+    }
+}
@@ -2,14 +2,27 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::ListArray;
+use common_arrow::arrow::array::ListBuilder;
+use common_arrow::arrow::array::TimestampMicrosecondArray;
+use common_arrow::arrow::array::TimestampMicrosecondBuilder;
+use common_arrow::arrow::array::UInt16Array;
+use common_arrow::arrow::array::UInt16Builder;
 use common_exception::Result;
 
 use crate::arrays::builders::*;
 use crate::arrays::get_list_builder;
+use crate::arrays::ops::scatter::assert_chunk_types;
+use crate::arrays::ops::scatter::assert_list_builder_types;
 use crate::arrays::ops::scatter::ArrayScatter;
 use crate::prelude::*;
 use crate::DFBooleanArray;
+use crate::DFFloat32Array;
+use crate::DFFloat64Array;
+use crate::DFListArray;
 use crate::DFUInt16Array;
+use crate::DFUInt32Array;
 use crate::DFUtf8Array;
 
 #[test]
@@ -87,3 +100,752 @@ fn test_scatter() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_scatter_checked() -> Result<()> {
+    let df_uint16_array = DFUInt16Array::new_from_iter(1u16..5u16);
+
+    let array_vec = df_uint16_array.scatter(&[1, 0, 1, 0], 2)?;
+    assert_eq!(&[2u16, 4], &array_vec[0].as_ref().values());
+    assert_eq!(&[1u16, 3], &array_vec[1].as_ref().values());
+
+    let result = df_uint16_array.scatter(&[1, 0, 1], 2);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Scatter indices length must be equal to the array length, got: 3, expect: 4"
+    );
+
+    let result = df_uint16_array.scatter(&[1, 0, 1, 0, 1], 2);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Scatter indices length must be equal to the array length, got: 5, expect: 4"
+    );
+
+    let result = df_uint16_array.scatter(&[1, 0, 2, 0], 2);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Scatter index 2 is out of range, the scattered size is 2"
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "scatter_unchecked: indices yield fewer items")]
+fn test_scatter_unchecked_short_indices() {
+    let df_uint16_array = DFUInt16Array::new_from_iter(1u16..5u16);
+    let indices: Vec<u64> = vec![1, 0, 1];
+    let _ = unsafe { df_uint16_array.scatter_unchecked(&mut indices.into_iter(), 2) };
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "scatter_unchecked: indices yield more items")]
+fn test_scatter_unchecked_long_indices() {
+    let df_utf8_array = DFUtf8Array::new_from_slice(&["a", "b"]);
+    let indices: Vec<u64> = vec![1, 0, 1];
+    let _ = unsafe { df_utf8_array.scatter_unchecked(&mut indices.into_iter(), 2) };
+}
+
+#[test]
+fn test_gather_chunks() -> Result<()> {
+    let indices: Vec<u64> = vec![1, 2, 0, 1, 2, 0, 0];
+
+    // Test DFUInt16Array
+    let df_uint16_array = DFUInt16Array::new_from_opt_slice(&[
+        Some(1u16),
+        None,
+        Some(3),
+        Some(4),
+        Some(5),
+        None,
+        Some(7),
+    ]);
+    let chunks = df_uint16_array.scatter(&indices, 3)?;
+    let gathered = DFUInt16Array::gather_chunks(&chunks, &indices)?;
+    assert_eq!(df_uint16_array.collect_values(), gathered.collect_values());
+
+    // Test DFUtf8Array
+    let df_utf8_array = DFUtf8Array::new_from_opt_slice(&[
+        Some("a"),
+        Some("bb"),
+        None,
+        Some("dddd"),
+        Some(""),
+        Some("f"),
+        None,
+    ]);
+    let chunks = df_utf8_array.scatter(&indices, 3)?;
+    let gathered = DFUtf8Array::gather_chunks(&chunks, &indices)?;
+    assert_eq!(df_utf8_array.collect_values(), gathered.collect_values());
+
+    // Test DFListArray of lists
+    let df_list = nested_list_of_u16()?;
+    let list_indices = vec![1, 0, 1];
+    let chunks = df_list.scatter(&list_indices, 2)?;
+    let gathered = DFListArray::gather_chunks(&chunks, &list_indices)?;
+    assert_eq!(gathered.data_type(), df_list.data_type());
+    assert_eq!(nested_u16(&gathered), nested_u16(&df_list));
+
+    // Index out of range
+    let result = DFUInt16Array::gather_chunks(&chunks_of_u16(), &[0, 3]);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Gather index 3 is out of range, the number of chunks is 2"
+    );
+
+    // More indices than rows
+    let result = DFUInt16Array::gather_chunks(&chunks_of_u16(), &[0, 0, 1]);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Gather chunk 0 has only 1 rows, but more are referenced by indices"
+    );
+
+    // Fewer indices than rows
+    let result = DFUInt16Array::gather_chunks(&chunks_of_u16(), &[0]);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Gather chunk 1 has 1 rows, but only 0 are referenced by indices"
+    );
+
+    Ok(())
+}
+
+fn chunks_of_u16() -> Vec<DFUInt16Array> {
+    vec![
+        DFUInt16Array::new_from_slice(&[1u16]),
+        DFUInt16Array::new_from_slice(&[2u16]),
+    ]
+}
+
+#[test]
+fn test_scatter_null_list() -> Result<()> {
+    let mut builder = get_list_builder(&DataType::UInt16, 6, 4);
+    builder.append_series(&Series::new(vec![1_u16, 2]));
+    builder.append_opt_series(None);
+    builder.append_series(&Series::new(Vec::<u16>::new()));
+    builder.append_opt_series(None);
+    let df_list = builder.finish();
+    assert_eq!(df_list.null_count(), 2);
+
+    let indices = vec![0, 0, 1, 1];
+    let array_vec = unsafe { df_list.scatter_unchecked(&mut indices.into_iter(), 2)? };
+
+    // chunk 0: [1, 2], NULL
+    let chunk = array_vec[0].downcast_ref();
+    assert_eq!(chunk.len(), 2);
+    assert_eq!(chunk.null_count(), 1);
+    assert!(chunk.is_valid(0));
+    assert_eq!(chunk.value_length(0), 2);
+    assert!(chunk.is_null(1));
+
+    // chunk 1: [], NULL
+    let chunk = array_vec[1].downcast_ref();
+    assert_eq!(chunk.len(), 2);
+    assert_eq!(chunk.null_count(), 1);
+    assert!(chunk.is_valid(0));
+    assert_eq!(chunk.value_length(0), 0);
+    assert!(chunk.is_null(1));
+    assert_eq!(chunk.values().len(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_scatter_u64_indices() -> Result<()> {
+    let df_uint16_array = DFUInt16Array::new_from_iter(1u16..7u16);
+
+    // Indices derived from a row range beyond u32::MAX
+    let base = u32::MAX as u64 + 1;
+    let indices = (base..base + 6).map(|row| row % 3).collect::<Vec<u64>>();
+    let array_vec = df_uint16_array.scatter(&indices, 3)?;
+    assert_eq!(&[3u16, 6], &array_vec[0].as_ref().values());
+    assert_eq!(&[1u16, 4], &array_vec[1].as_ref().values());
+    assert_eq!(&[2u16, 5], &array_vec[2].as_ref().values());
+
+    // An index that would wrap to 0 if truncated to 32 bits must be rejected
+    let result = df_uint16_array.scatter(&[0, 1, 2, base, 1, 0], 3);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Scatter index 4294967296 is out of range, the scattered size is 3"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_hash_partition() -> Result<()> {
+    let values = vec![1u16, 2, 3, 1, 2, 3, 1, 7, 7, 9];
+    let df_uint16_array = DFUInt16Array::new_from_slice(&values);
+    let array_vec = df_uint16_array.hash_partition(3)?;
+    assert_eq!(array_vec.len(), 3);
+
+    // Equal values are co-located
+    for value in values.iter() {
+        let chunks = array_vec
+            .iter()
+            .filter(|chunk| chunk.as_ref().values().contains(value))
+            .count();
+        assert_eq!(chunks, 1);
+    }
+
+    // The union of the chunks is the input multiset
+    let mut scattered = array_vec
+        .iter()
+        .flat_map(|chunk| chunk.as_ref().values().to_vec())
+        .collect::<Vec<_>>();
+    scattered.sort_unstable();
+    let mut expected = values.clone();
+    expected.sort_unstable();
+    assert_eq!(scattered, expected);
+
+    // The partition is deterministic
+    let again = df_uint16_array.hash_partition(3)?;
+    for (chunk, expected) in again.iter().zip(array_vec.iter()) {
+        assert_eq!(chunk.collect_values(), expected.collect_values());
+    }
+
+    // Nulls go to chunk 0
+    let df_utf8_array = DFUtf8Array::new_from_opt_slice(&[Some("a"), None, Some("a"), None]);
+    let array_vec = df_utf8_array.hash_partition(4)?;
+    assert_eq!(array_vec[0].null_count(), 2);
+    assert_eq!(array_vec.iter().map(|chunk| chunk.len()).sum::<usize>(), 4);
+
+    let result = df_uint16_array.hash_partition(0);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Hash partition requires at least one chunk"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_scatter_float() -> Result<()> {
+    // Test DFFloat32Array
+    let df_float32_array =
+        DFFloat32Array::new_from_opt_slice(&[Some(1.5f32), None, Some(-2.0), Some(3.25)]);
+    let indices = vec![1, 0, 1, 0];
+    let array_vec = unsafe { df_float32_array.scatter_unchecked(&mut indices.into_iter(), 2)? };
+    assert_eq!(array_vec[0].collect_values(), vec![None, Some(3.25f32)]);
+    assert_eq!(array_vec[1].collect_values(), vec![
+        Some(1.5f32),
+        Some(-2.0)
+    ]);
+
+    // Test DFFloat64Array
+    let df_float64_array = DFFloat64Array::new_from_slice(&[0.5f64, 1.5, 2.5, f64::INFINITY]);
+    let indices = vec![0, 0, 1, 1];
+    let array_vec = unsafe { df_float64_array.scatter_unchecked(&mut indices.into_iter(), 2)? };
+    assert_eq!(&[0.5f64, 1.5], &array_vec[0].as_ref().values());
+    assert_eq!(&[2.5f64, f64::INFINITY], &array_vec[1].as_ref().values());
+
+    // NaNs with different bit patterns land in the same chunk
+    let other_nan = f64::from_bits(0x7ff8_0000_0000_0001);
+    assert!(other_nan.is_nan());
+    let df_float64_array = DFFloat64Array::new_from_slice(&[1.0f64, f64::NAN, 2.0, -other_nan]);
+    let array_vec = df_float64_array.hash_partition(8)?;
+    let nan_chunks = array_vec
+        .iter()
+        .filter(|chunk| chunk.as_ref().values().iter().any(|v| v.is_nan()))
+        .collect::<Vec<_>>();
+    assert_eq!(nan_chunks.len(), 1);
+    assert_eq!(
+        nan_chunks[0]
+            .as_ref()
+            .values()
+            .iter()
+            .filter(|v| v.is_nan())
+            .count(),
+        2
+    );
+
+    let df_float32_array = DFFloat32Array::new_from_slice(&[f32::NAN, 1.0f32, -f32::NAN]);
+    let array_vec = df_float32_array.hash_partition(8)?;
+    let nan_chunks = array_vec
+        .iter()
+        .filter(|chunk| chunk.as_ref().values().iter().any(|v| v.is_nan()))
+        .count();
+    assert_eq!(nan_chunks, 1);
+
+    // -0.0 and 0.0 are equal and land in the same chunk
+    let df_float64_array = DFFloat64Array::new_from_slice(&[0.0f64, 1.0, -0.0, 2.0]);
+    let array_vec = df_float64_array.hash_partition(8)?;
+    let zero_chunks = array_vec
+        .iter()
+        .filter(|chunk| chunk.as_ref().values().iter().any(|v| *v == 0.0))
+        .collect::<Vec<_>>();
+    assert_eq!(zero_chunks.len(), 1);
+    assert_eq!(
+        zero_chunks[0]
+            .as_ref()
+            .values()
+            .iter()
+            .filter(|v| **v == 0.0)
+            .count(),
+        2
+    );
+
+    let df_float32_array = DFFloat32Array::new_from_slice(&[-0.0f32, 1.0, 0.0]);
+    let array_vec = df_float32_array.hash_partition(8)?;
+    let zero_chunks = array_vec
+        .iter()
+        .filter(|chunk| chunk.as_ref().values().iter().any(|v| *v == 0.0))
+        .count();
+    assert_eq!(zero_chunks, 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_scatter_into() -> Result<()> {
+    // Two successive batches append into the same builders
+    let mut builders = (0..2)
+        .map(|_| DFUInt16ArrayBuilder::new(4))
+        .collect::<Vec<_>>();
+    let first = DFUInt16Array::new_from_iter(1u16..4u16);
+    first.scatter_into(&[0u64, 1, 0], &mut builders)?;
+    let second = DFUInt16Array::new_from_opt_slice(&[Some(4u16), None, Some(6)]);
+    second.scatter_into(&[1u64, 1, 0], &mut builders)?;
+
+    let array_vec = builders
+        .iter_mut()
+        .map(|builder| builder.finish())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![Some(1u16), Some(3), Some(6)],
+        array_vec[0].collect_values()
+    );
+    assert_eq!(
+        vec![Some(2u16), Some(4), None],
+        array_vec[1].collect_values()
+    );
+
+    let mut builders = (0..2)
+        .map(|_| Utf8ArrayBuilder::new(4, 16))
+        .collect::<Vec<_>>();
+    let first = DFUtf8Array::new_from_slice(&["a", "b"]);
+    first.scatter_into(&[1u64, 0], &mut builders)?;
+    let second = DFUtf8Array::new_from_slice(&["c", "d"]);
+    second.scatter_into(&[1u64, 1], &mut builders)?;
+
+    let array_vec = builders
+        .iter_mut()
+        .map(|builder| builder.finish())
+        .collect::<Vec<_>>();
+    assert_eq!(vec![Some("b")], array_vec[0].collect_values());
+    assert_eq!(
+        vec![Some("a"), Some("c"), Some("d")],
+        array_vec[1].collect_values()
+    );
+
+    let mut builders = (0..2)
+        .map(|_| get_list_builder(&DataType::UInt16, 4, 2))
+        .collect::<Vec<_>>();
+    let mut list_builder = get_list_builder(&DataType::UInt16, 3, 2);
+    list_builder.append_series(&Series::new(vec![1_u16, 2]));
+    list_builder.append_opt_series(None);
+    let first = list_builder.finish();
+    first.scatter_into(&[1u64, 0], &mut builders)?;
+    let mut list_builder = get_list_builder(&DataType::UInt16, 1, 1);
+    list_builder.append_series(&Series::new(vec![3_u16]));
+    let second = list_builder.finish();
+    second.scatter_into(&[1u64], &mut builders)?;
+
+    let array_vec = builders
+        .iter_mut()
+        .map(|builder| builder.finish())
+        .collect::<Vec<_>>();
+    assert_eq!(array_vec[0].len(), 1);
+    assert_eq!(array_vec[0].null_count(), 1);
+    let lists = array_vec[1]
+        .downcast_iter()
+        .map(|s| s.map(|s| s.u16().unwrap().collect_values()))
+        .collect::<Vec<_>>();
+    assert_eq!(lists, vec![
+        Some(vec![Some(1u16), Some(2)]),
+        Some(vec![Some(3u16)])
+    ]);
+
+    // List builders of another value type are rejected before anything is appended
+    let mut builders = vec![
+        get_list_builder(&DataType::UInt16, 4, 2),
+        get_list_builder(&DataType::Utf8, 4, 2),
+    ];
+    let mut list_builder = get_list_builder(&DataType::UInt16, 1, 1);
+    list_builder.append_series(&Series::new(vec![4_u16]));
+    let result = list_builder.finish().scatter_into(&[0u64], &mut builders);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Scatter builder 1 builds lists of Utf8, but the array has lists of UInt16"
+    );
+    assert_eq!(0, builders[0].finish().len());
+    assert_eq!(0, builders[1].finish().len());
+
+    // An index beyond the builders is rejected before anything is appended
+    let df_uint16_array = DFUInt16Array::new_from_iter(1u16..4u16);
+    let mut builders = vec![DFUInt16ArrayBuilder::new(4)];
+    let result = df_uint16_array.scatter_into(&[0u64, 1, 0], &mut builders);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Scatter index 1 is out of range, the number of builders is 1"
+    );
+    assert_eq!(0, builders[0].finish().len());
+
+    let result = df_uint16_array.scatter_into(&[0u64], &mut builders);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Scatter indices length must be equal to the array length, got: 1, expect: 3"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_scatter_chunk_types() -> Result<()> {
+    // The inner type of lists is preserved across chunks
+    let mut builder = get_list_builder(&DataType::Utf8, 8, 3);
+    builder.append_series(&Series::new(vec!["a", "b"]));
+    builder.append_opt_series(None);
+    builder.append_series(&Series::new(vec!["c"]));
+    let df_list = builder.finish();
+
+    let array_vec = df_list.scatter(&[1, 0, 1], 3)?;
+    assert_chunk_types(&df_list, &array_vec)?;
+    for chunk in array_vec.iter() {
+        assert_eq!(chunk.sub_data_type(), DataType::Utf8);
+    }
+
+    // A chunk of a different inner type is rejected
+    let mut builder = get_list_builder(&DataType::UInt16, 2, 1);
+    builder.append_series(&Series::new(vec![1_u16, 2]));
+    let other_list = builder.finish();
+
+    let result = assert_chunk_types(&df_list, &[array_vec[0].clone(), other_list]);
+    assert!(result
+        .unwrap_err()
+        .message()
+        .starts_with("Scattered chunk 1 has data type"));
+
+    // Chunks built by scatter_into() keep the inner type as well
+    let mut builders = (0..2)
+        .map(|_| get_list_builder(&DataType::Utf8, 8, 3))
+        .collect::<Vec<_>>();
+    assert_list_builder_types(&df_list, &builders)?;
+    df_list.scatter_into(&[1, 0, 1], &mut builders)?;
+    let array_vec = builders
+        .iter_mut()
+        .map(|builder| builder.finish())
+        .collect::<Vec<_>>();
+    assert_chunk_types(&df_list, &array_vec)?;
+
+    // Builders of a different inner type are rejected
+    let builders = vec![
+        get_list_builder(&DataType::Utf8, 8, 3),
+        get_list_builder(&DataType::Boolean, 8, 3),
+    ];
+    let result = assert_list_builder_types(&df_list, &builders);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Scatter builder 1 builds lists of Boolean, but the array has lists of Utf8"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_scatter_empty() -> Result<()> {
+    // An empty array yields the requested number of empty chunks of the same type
+    let df_uint16_array = DFUInt16Array::new_from_slice(&Vec::<u16>::new());
+    let array_vec = df_uint16_array.scatter(&[], 3)?;
+    assert_eq!(array_vec.len(), 3);
+    for chunk in array_vec.iter() {
+        assert_eq!(chunk.len(), 0);
+        assert_eq!(chunk.data_type(), DataType::UInt16);
+    }
+
+    let df_utf8_array = DFUtf8Array::new_from_slice(&Vec::<&str>::new());
+    let array_vec =
+        unsafe { df_utf8_array.scatter_unchecked(&mut Vec::<u64>::new().into_iter(), 2)? };
+    assert_eq!(array_vec.len(), 2);
+    for chunk in array_vec.iter() {
+        assert_eq!(chunk.len(), 0);
+        assert_eq!(chunk.data_type(), DataType::Utf8);
+    }
+
+    let df_list = get_list_builder(&DataType::UInt16, 0, 0).finish();
+    let array_vec = df_list.scatter(&[], 2)?;
+    assert_eq!(array_vec.len(), 2);
+    for chunk in array_vec.iter() {
+        assert_eq!(chunk.len(), 0);
+        assert_eq!(chunk.sub_data_type(), DataType::UInt16);
+    }
+
+    // Zero chunks is rejected, with or without rows
+    let result = df_uint16_array.scatter(&[], 0);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Scatter requires at least one chunk"
+    );
+
+    let df_uint16_array = DFUInt16Array::new_from_slice(&[1u16, 2]);
+    let result = df_uint16_array.scatter(&[0, 0], 0);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Scatter requires at least one chunk"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_partition_sizes() -> Result<()> {
+    let indices = vec![1, 2, 3, 1, 3, 2, 0, 3, 1, 0];
+    assert_eq!(
+        vec![2, 3, 2, 3],
+        DFUInt16Array::partition_sizes(&indices, 4)?
+    );
+
+    // Sizes agree with the chunks actually produced
+    let df_uint16_array = DFUInt16Array::new_from_iter(1u16..11u16);
+    let array_vec = df_uint16_array.scatter(&indices, 4)?;
+    let chunk_sizes = array_vec
+        .iter()
+        .map(|chunk| chunk.len())
+        .collect::<Vec<_>>();
+    assert_eq!(chunk_sizes, DFUInt16Array::partition_sizes(&indices, 4)?);
+
+    let result = DFUInt16Array::partition_sizes(&indices, 3);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Scatter index 3 is out of range, the scattered size is 3"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_scatter_uneven_list() -> Result<()> {
+    let long = (0..1000u16).collect::<Vec<_>>();
+    let mut builder = get_list_builder(&DataType::UInt16, 1002, 4);
+    builder.append_series(&Series::new(long));
+    builder.append_series(&Series::new(vec![7_u16]));
+    builder.append_opt_series(None);
+    builder.append_series(&Series::new(vec![8_u16, 9]));
+    let df_list = builder.finish();
+
+    let array_vec = df_list.scatter(&[0, 1, 1, 1], 3)?;
+
+    // chunk 0: the single long list
+    let chunk = array_vec[0].downcast_ref();
+    assert_eq!(chunk.len(), 1);
+    assert_eq!(chunk.value_length(0), 1000);
+    assert_eq!(chunk.values().len(), 1000);
+
+    // chunk 1: [7], NULL, [8, 9]
+    let chunk = array_vec[1].downcast_ref();
+    assert_eq!(chunk.len(), 3);
+    assert_eq!(chunk.value_length(0), 1);
+    assert!(chunk.is_null(1));
+    assert_eq!(chunk.value_length(2), 2);
+    assert_eq!(chunk.values().len(), 3);
+
+    // chunk 2: nothing is routed to it
+    assert_eq!(array_vec[2].len(), 0);
+
+    let values = array_vec[1].downcast_iter().collect::<Vec<_>>();
+    assert!(values[0]
+        .as_ref()
+        .unwrap()
+        .series_equal(&Series::new(vec![7_u16])));
+    assert!(values[2]
+        .as_ref()
+        .unwrap()
+        .series_equal(&Series::new(vec![8_u16, 9])));
+
+    Ok(())
+}
+
+#[test]
+fn test_scatter_by_ids() -> Result<()> {
+    let df_uint16_array = DFUInt16Array::new_from_iter(1u16..11u16);
+    let indices = vec![1u32, 2, 3, 1, 3, 2, 0, 3, 1, 0];
+    let chunk_ids = DFUInt32Array::new_from_slice(&indices);
+
+    let array_vec = df_uint16_array.scatter_by_ids(&chunk_ids, 4)?;
+    let expected =
+        unsafe { df_uint16_array.scatter_unchecked(&mut indices.iter().map(|id| *id as u64), 4)? };
+    assert_eq!(array_vec.len(), expected.len());
+    for (chunk, expected) in array_vec.iter().zip(expected.iter()) {
+        assert_eq!(chunk.collect_values(), expected.collect_values());
+    }
+
+    let chunk_ids = DFUInt32Array::new_from_opt_slice(&[Some(0u32), None, Some(1)]);
+    let result = DFUInt16Array::new_from_iter(1u16..4u16).scatter_by_ids(&chunk_ids, 2);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Scatter chunk id at row 1 is null"
+    );
+
+    let chunk_ids = DFUInt32Array::new_from_slice(&[0u32, 2, 1]);
+    let result = DFUInt16Array::new_from_iter(1u16..4u16).scatter_by_ids(&chunk_ids, 2);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Scatter index 2 is out of range, the scattered size is 2"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_scatter_nested_list() -> Result<()> {
+    // [[1, 2], [3]], [[4]], [NULL, [5, 6]]
+    let df_list = nested_list_of_u16()?;
+
+    let array_vec = df_list.scatter(&[0, 1, 0], 2)?;
+    assert_eq!(nested_u16(&array_vec[0]), vec![
+        vec![Some(vec![1, 2]), Some(vec![3])],
+        vec![None, Some(vec![5, 6])],
+    ]);
+    assert_eq!(nested_u16(&array_vec[1]), vec![vec![Some(vec![4])]]);
+
+    for chunk in array_vec.iter() {
+        assert_eq!(chunk.data_type(), df_list.data_type());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_scatter_timestamp_list() -> Result<()> {
+    // [1, 2], NULL, [3]
+    let mut builder = ListBuilder::new(TimestampMicrosecondBuilder::new(3));
+    builder.values().append_slice(&[1, 2])?;
+    builder.append(true)?;
+    builder.append(false)?;
+    builder.values().append_value(3)?;
+    builder.append(true)?;
+    let df_list = DFListArray::from_arrow_array(builder.finish());
+
+    let array_vec = df_list.scatter(&[1, 0, 1], 2)?;
+    assert_eq!(array_vec[0].len(), 1);
+    assert_eq!(array_vec[0].null_count(), 1);
+
+    let chunk = array_vec[1].downcast_ref();
+    assert_eq!(chunk.len(), 2);
+    assert_eq!(chunk.null_count(), 0);
+    let values = chunk.values();
+    let values = values
+        .as_any()
+        .downcast_ref::<TimestampMicrosecondArray>()
+        .unwrap();
+    assert_eq!(values.values(), &[1, 2, 3]);
+
+    for chunk in array_vec.iter() {
+        assert_eq!(chunk.data_type(), df_list.data_type());
+    }
+
+    Ok(())
+}
+
+fn nested_list_of_u16() -> Result<DFListArray> {
+    let mut builder = ListBuilder::new(ListBuilder::new(UInt16Builder::new(6)));
+    builder.values().values().append_slice(&[1, 2])?;
+    builder.values().append(true)?;
+    builder.values().values().append_value(3)?;
+    builder.values().append(true)?;
+    builder.append(true)?;
+    builder.values().values().append_value(4)?;
+    builder.values().append(true)?;
+    builder.append(true)?;
+    builder.values().append(false)?;
+    builder.values().values().append_slice(&[5, 6])?;
+    builder.values().append(true)?;
+    builder.append(true)?;
+    Ok(DFListArray::from_arrow_array(builder.finish()))
+}
+
+fn nested_u16(list: &DFListArray) -> Vec<Vec<Option<Vec<u16>>>> {
+    let list = list.downcast_ref();
+    (0..list.len())
+        .map(|row| {
+            let inner = list.value(row);
+            let inner = inner.as_any().downcast_ref::<ListArray>().unwrap();
+            (0..inner.len())
+                .map(|i| match inner.is_null(i) {
+                    true => None,
+                    false => {
+                        let values = inner.value(i);
+                        let values = values.as_any().downcast_ref::<UInt16Array>().unwrap();
+                        Some(values.values().to_vec())
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn test_scatter_varied_length_utf8() -> Result<()> {
+    let df_utf8_array = DFUtf8Array::new_from_opt_slice(&[
+        Some("a"),
+        Some("datafuse"),
+        None,
+        Some(""),
+        Some("query engine"),
+        Some("xy"),
+    ]);
+    let array_vec = df_utf8_array.scatter(&[0, 1, 0, 0, 1, 0], 2)?;
+
+    let chunk = array_vec[0].downcast_ref();
+    assert_eq!(chunk.len(), 4);
+    assert_eq!(chunk.value_data().as_slice(), b"axy");
+    assert_eq!(array_vec[0].collect_values(), vec![
+        Some("a"),
+        None,
+        Some(""),
+        Some("xy")
+    ]);
+
+    let chunk = array_vec[1].downcast_ref();
+    assert_eq!(chunk.len(), 2);
+    assert_eq!(chunk.value_data().as_slice(), b"datafusequery engine");
+
+    // Binary goes through the same byte counting
+    let mut builder = BinaryArrayBuilder::new(16);
+    builder.append_value(b"12");
+    builder.append_null();
+    builder.append_value(b"345");
+    let df_binary_array = builder.finish();
+    let array_vec = df_binary_array.scatter(&[1, 1, 0], 2)?;
+    assert_eq!(array_vec[0].downcast_ref().value_data().as_slice(), b"345");
+    assert_eq!(array_vec[1].downcast_ref().value_data().as_slice(), b"12");
+    assert_eq!(array_vec[1].null_count(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_round_robin() -> Result<()> {
+    let df_uint16_array = DFUInt16Array::new_from_iter(0u16..11u16);
+    let array_vec = df_uint16_array.round_robin(4)?;
+
+    let sizes = array_vec
+        .iter()
+        .map(|chunk| chunk.len())
+        .collect::<Vec<_>>();
+    assert_eq!(vec![3, 3, 3, 2], sizes);
+    assert_eq!(&[0u16, 4, 8], &array_vec[0].as_ref().values());
+    assert_eq!(&[1u16, 5, 9], &array_vec[1].as_ref().values());
+    assert_eq!(&[2u16, 6, 10], &array_vec[2].as_ref().values());
+    assert_eq!(&[3u16, 7], &array_vec[3].as_ref().values());
+
+    let result = df_uint16_array.round_robin(0);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Scatter requires at least one chunk"
+    );
+
+    Ok(())
+}
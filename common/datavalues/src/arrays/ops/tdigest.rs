@@ -0,0 +1,188 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::DFFloat64Array;
+
+const TDIGEST_COMPRESSION: f64 = 100.0;
+// Values are buffered and compressed into the digest in batches of this size,
+// which bounds the memory of building a digest independently of the input size.
+const TDIGEST_BUFFER_SIZE: usize = 5 * TDIGEST_COMPRESSION as usize;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// A mergeable sketch for approximate quantiles with bounded memory.
+/// Values are summarized into at most about `compression` centroids, which are
+/// smaller near the tails so extreme quantiles stay accurate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TDigest {
+    centroids: Vec<Centroid>,
+    count: f64,
+    min: f64,
+    max: f64,
+}
+
+impl TDigest {
+    pub fn new() -> Self {
+        TDigest {
+            centroids: vec![],
+            count: 0.0,
+            min: f64::NAN,
+            max: f64::NAN,
+        }
+    }
+
+    /// The number of values summarized by the digest.
+    pub fn count(&self) -> u64 {
+        self.count as u64
+    }
+
+    /// Merge two digests, as if one was built over the values of both.
+    pub fn merge(&self, other: &TDigest) -> TDigest {
+        let mut centroids = Vec::with_capacity(self.centroids.len() + other.centroids.len());
+        centroids.extend_from_slice(&self.centroids);
+        centroids.extend_from_slice(&other.centroids);
+
+        TDigest {
+            centroids: compress(centroids),
+            count: self.count + other.count,
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// Estimate the q-quantile, returns None if the digest is empty.
+    /// Returns a BadArguments error if q is not in the range [0, 1].
+    pub fn quantile(&self, q: f64) -> Result<Option<f64>> {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(ErrorCode::BadArguments(format!(
+                "Quantile must be in the range [0, 1], got: {}",
+                q
+            )));
+        }
+
+        if self.centroids.is_empty() {
+            return Ok(None);
+        }
+
+        // Every centroid is taken to sit at the middle of its weight, values are
+        // interpolated between neighbouring centers and the min/max at both ends.
+        let target = q * self.count;
+        let mut cumulative = 0.0;
+        let (mut prev_center, mut prev_mean) = (0.0, self.min);
+        for centroid in self.centroids.iter() {
+            let center = cumulative + centroid.weight / 2.0;
+            if target < center {
+                return Ok(Some(interpolate(
+                    target,
+                    (prev_center, prev_mean),
+                    (center, centroid.mean),
+                )));
+            }
+            cumulative += centroid.weight;
+            prev_center = center;
+            prev_mean = centroid.mean;
+        }
+
+        Ok(Some(interpolate(
+            target,
+            (prev_center, prev_mean),
+            (self.count, self.max),
+        )))
+    }
+}
+
+impl Default for TDigest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn interpolate(x: f64, (x0, y0): (f64, f64), (x1, y1): (f64, f64)) -> f64 {
+    if x1 <= x0 {
+        return y1;
+    }
+    y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+}
+
+/// Merge neighbouring centroids while each stays within the size bound
+/// `4 * count * q * (1 - q) / compression` of its quantile q.
+fn compress(mut centroids: Vec<Centroid>) -> Vec<Centroid> {
+    if centroids.len() <= 1 {
+        return centroids;
+    }
+
+    centroids.sort_by(|a, b| a.mean.partial_cmp(&b.mean).unwrap());
+    let count = centroids
+        .iter()
+        .map(|centroid| centroid.weight)
+        .sum::<f64>();
+
+    let mut result = Vec::new();
+    let mut current = centroids[0];
+    let mut weight_before = 0.0;
+    for centroid in centroids.into_iter().skip(1) {
+        let weight = current.weight + centroid.weight;
+        let q = (weight_before + weight / 2.0) / count;
+        let limit = 4.0 * count * q * (1.0 - q) / TDIGEST_COMPRESSION;
+
+        if weight <= limit.max(1.0) {
+            current.mean += (centroid.mean - current.mean) * centroid.weight / weight;
+            current.weight = weight;
+        } else {
+            weight_before += current.weight;
+            result.push(current);
+            current = centroid;
+        }
+    }
+    result.push(current);
+    result
+}
+
+impl DFFloat64Array {
+    /// Summarize the non-null values into a t-digest, NaN values are rejected.
+    pub fn tdigest(&self) -> Result<TDigest> {
+        let mut digest = TDigest::new();
+        let mut buffer = Vec::with_capacity(TDIGEST_BUFFER_SIZE);
+        for value in self.downcast_iter().flatten() {
+            if value.is_nan() {
+                return Err(ErrorCode::BadDataValueType(
+                    "Cannot build a t-digest over NaN values",
+                ));
+            }
+            buffer.push(Centroid {
+                mean: value,
+                weight: 1.0,
+            });
+            digest.min = digest.min.min(value);
+            digest.max = digest.max.max(value);
+
+            if buffer.len() == TDIGEST_BUFFER_SIZE {
+                digest.compress_buffer(&mut buffer);
+            }
+        }
+
+        digest.compress_buffer(&mut buffer);
+        Ok(digest)
+    }
+}
+
+impl TDigest {
+    /// Compress the buffered unit-weight centroids into the digest and empty the buffer.
+    fn compress_buffer(&mut self, buffer: &mut Vec<Centroid>) {
+        if buffer.is_empty() {
+            return;
+        }
+
+        self.count += buffer.len() as f64;
+        buffer.extend_from_slice(&self.centroids);
+        self.centroids = compress(buffer.drain(..).collect());
+    }
+}
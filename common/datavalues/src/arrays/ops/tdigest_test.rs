@@ -0,0 +1,58 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_exception::Result;
+
+use crate::arrays::ops::tdigest::TDigest;
+use crate::prelude::*;
+use crate::DFFloat64Array;
+
+#[test]
+fn test_tdigest() -> Result<()> {
+    // Two partitions with a null each, the combined median is 4999.5
+    let left = (0..5000)
+        .map(|v| Some(v as f64))
+        .chain(std::iter::once(None))
+        .collect::<Vec<_>>();
+    let right = (5000..10000)
+        .rev()
+        .map(|v| Some(v as f64))
+        .chain(std::iter::once(None))
+        .collect::<Vec<_>>();
+    let left = DFFloat64Array::new_from_opt_slice(&left).tdigest()?;
+    let right = DFFloat64Array::new_from_opt_slice(&right).tdigest()?;
+
+    let digest = left.merge(&right);
+    assert_eq!(digest.count(), 10000);
+
+    let median = digest.quantile(0.5)?.unwrap();
+    assert!((median - 4999.5).abs() < 50.0, "median: {}", median);
+    let p99 = digest.quantile(0.99)?.unwrap();
+    assert!((p99 - 9899.5).abs() < 50.0, "p99: {}", p99);
+    assert_eq!(digest.quantile(0.0)?, Some(0.0));
+    assert_eq!(digest.quantile(1.0)?, Some(9999.0));
+
+    // Merging is symmetric
+    let median = right.merge(&left).quantile(0.5)?.unwrap();
+    assert!((median - 4999.5).abs() < 50.0, "median: {}", median);
+
+    // Values are compressed in batches, a batch boundary loses no values
+    let batch = (0..500).map(|v| v as f64).collect::<Vec<_>>();
+    let batch = DFFloat64Array::new_from_slice(&batch).tdigest()?;
+    assert_eq!(batch.count(), 500);
+    assert_eq!(batch.quantile(1.0)?, Some(499.0));
+
+    let empty = DFFloat64Array::new_from_opt_slice(&[None]).tdigest()?;
+    assert_eq!(empty.count(), 0);
+    assert_eq!(empty.quantile(0.5)?, None);
+    assert_eq!(empty.merge(&TDigest::new()).quantile(0.5)?, None);
+
+    let result = digest.quantile(1.5);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Quantile must be in the range [0, 1], got: 1.5"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,225 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::arrays::get_list_builder;
+use crate::series::Series;
+use crate::series::SeriesFrom;
+use crate::DFBooleanArray;
+use crate::DFFloat64Array;
+use crate::DFListArray;
+use crate::DFUtf8Array;
+use crate::DataType;
+
+impl DFUtf8Array {
+    /// Split every string into a list of word tokens, null strings yield null lists.
+    /// Tokens are the words between Unicode word boundaries (UAX #29), so "It's" stays
+    /// one token and combining marks stay with their base letter, while whitespace and
+    /// punctuation are dropped.
+    pub fn tokenize(&self, lowercase: bool) -> Result<DFListArray> {
+        let array = self.downcast_ref();
+        // The list builder reserves 5 bytes per string value, estimate the tokens from
+        // the input size so the values buffer starts out as large as the input.
+        let tokens_capacity = array.value_data().len() / 5;
+        let mut builder = get_list_builder(&DataType::Utf8, tokens_capacity, self.len());
+
+        for row in 0..self.len() {
+            if self.is_null(row) {
+                builder.append_opt_series(None);
+                continue;
+            }
+
+            let lowered;
+            let value = match lowercase {
+                true => {
+                    lowered = array.value(row).to_lowercase();
+                    lowered.as_str()
+                }
+                false => array.value(row),
+            };
+            let tokens = value.unicode_words().collect::<Vec<_>>();
+            builder.append_series(&Series::new(tokens));
+        }
+
+        Ok(builder.finish())
+    }
+
+    /// SQL LIKE matching against `pattern`, where `%` matches any sequence of characters
+    /// and `_` matches exactly one. A character following `escape` is matched literally.
+    /// Null strings yield null.
+    /// Returns a BadArguments error if the pattern ends with the escape character.
+    pub fn like(&self, pattern: &str, escape: Option<char>) -> Result<DFBooleanArray> {
+        let pattern = parse_like_pattern(pattern, escape)?;
+        let array = self.downcast_ref();
+
+        Ok((0..self.len())
+            .map(|row| match self.is_null(row) {
+                true => None,
+                false => {
+                    let text = array.value(row).chars().collect::<Vec<_>>();
+                    Some(like_match(&text, &pattern))
+                }
+            })
+            .collect())
+    }
+
+    /// Compute the Jaro-Winkler similarity of every pair of strings, from 0 for no
+    /// similarity to 1 for identical strings, null if either side is null.
+    /// Returns a BadDataArrayLength error if the arrays have different lengths.
+    pub fn jaro_winkler(&self, other: &DFUtf8Array) -> Result<DFFloat64Array> {
+        if other.len() != self.len() {
+            return Err(ErrorCode::BadDataArrayLength(format!(
+                "cannot compute jaro_winkler, array size not matched, got: {}, expect: {}",
+                other.len(),
+                self.len()
+            )));
+        }
+
+        Ok(self
+            .downcast_iter()
+            .zip(other.downcast_iter())
+            .map(|pair| match pair {
+                (Some(lhs), Some(rhs)) => {
+                    let lhs = lhs.chars().collect::<Vec<_>>();
+                    let rhs = rhs.chars().collect::<Vec<_>>();
+                    Some(jaro_winkler(&lhs, &rhs))
+                }
+                _ => None,
+            })
+            .collect())
+    }
+}
+
+fn jaro(lhs: &[char], rhs: &[char]) -> f64 {
+    if lhs.is_empty() && rhs.is_empty() {
+        return 1.0;
+    }
+    if lhs.is_empty() || rhs.is_empty() {
+        return 0.0;
+    }
+
+    // Characters match if they are equal and no further apart than the window.
+    let window = (lhs.len().max(rhs.len()) / 2).saturating_sub(1);
+    let mut lhs_matched = vec![false; lhs.len()];
+    let mut rhs_matched = vec![false; rhs.len()];
+    let mut matches = 0usize;
+    for (i, c) in lhs.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(rhs.len());
+        for (j, matched) in rhs_matched.iter_mut().enumerate().take(end).skip(start) {
+            if !*matched && rhs[j] == *c {
+                lhs_matched[i] = true;
+                *matched = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+
+    if matches == 0 {
+        return 0.0;
+    }
+
+    // Half the number of matched characters that are out of order.
+    let lhs_chars = lhs.iter().zip(lhs_matched).filter(|(_, m)| *m);
+    let rhs_chars = rhs.iter().zip(rhs_matched).filter(|(_, m)| *m);
+    let transpositions = lhs_chars
+        .zip(rhs_chars)
+        .filter(|((l, _), (r, _))| l != r)
+        .count()
+        / 2;
+
+    let matches = matches as f64;
+    (matches / lhs.len() as f64
+        + matches / rhs.len() as f64
+        + (matches - transpositions as f64) / matches)
+        / 3.0
+}
+
+fn jaro_winkler(lhs: &[char], rhs: &[char]) -> f64 {
+    let jaro = jaro(lhs, rhs);
+    // Boost strings sharing a common prefix of up to 4 characters.
+    let prefix = lhs
+        .iter()
+        .zip(rhs.iter())
+        .take(4)
+        .take_while(|(l, r)| l == r)
+        .count();
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum LikeToken {
+    Literal(char),
+    AnyChar,
+    AnyString,
+}
+
+fn parse_like_pattern(pattern: &str, escape: Option<char>) -> Result<Vec<LikeToken>> {
+    let mut tokens = Vec::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        let token = match c {
+            c if Some(c) == escape => match chars.next() {
+                Some(c) => LikeToken::Literal(c),
+                None => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "LIKE pattern '{}' must not end with the escape character",
+                        pattern
+                    )))
+                }
+            },
+            '%' => LikeToken::AnyString,
+            '_' => LikeToken::AnyChar,
+            c => LikeToken::Literal(c),
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+/// Greedy wildcard matching, on a mismatch only the last `%` needs to be retried
+/// with one more character.
+fn like_match(text: &[char], pattern: &[LikeToken]) -> bool {
+    let (mut t, mut p) = (0, 0);
+    let mut last_any_string: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some(LikeToken::AnyString) => {
+                last_any_string = Some((p, t));
+                p += 1;
+                continue;
+            }
+            Some(LikeToken::AnyChar) => {
+                t += 1;
+                p += 1;
+                continue;
+            }
+            Some(LikeToken::Literal(c)) if *c == text[t] => {
+                t += 1;
+                p += 1;
+                continue;
+            }
+            _ => {}
+        }
+
+        match last_any_string {
+            // Let the last % absorb one more character and retry
+            Some((any_p, any_t)) => {
+                last_any_string = Some((any_p, any_t + 1));
+                p = any_p + 1;
+                t = any_t + 1;
+            }
+            None => return false,
+        }
+    }
+
+    pattern[p..]
+        .iter()
+        .all(|token| *token == LikeToken::AnyString)
+}
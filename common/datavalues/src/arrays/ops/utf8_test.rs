@@ -0,0 +1,206 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_exception::Result;
+
+use crate::prelude::*;
+use crate::DFListArray;
+use crate::DFUtf8Array;
+
+#[test]
+fn test_utf8_tokenize() -> Result<()> {
+    let df_utf8_array = DFUtf8Array::new_from_opt_slice(&[
+        Some("Hello, World! It's  datafuse-2021."),
+        None,
+        Some("..."),
+        Some("Grüße aus Köln"),
+        Some("cafe\u{301} noir"),
+    ]);
+
+    let tokens = |list: &DFListArray| {
+        list.downcast_iter()
+            .map(|s| {
+                s.map(|s| {
+                    s.utf8()
+                        .unwrap()
+                        .collect_values()
+                        .iter()
+                        .map(|v| v.unwrap().to_string())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let list = df_utf8_array.tokenize(false)?;
+    assert_eq!(list.null_count(), 1);
+    assert_eq!(tokens(&list), vec![
+        Some(vec![
+            "Hello".to_string(),
+            "World".to_string(),
+            "It's".to_string(),
+            "datafuse".to_string(),
+            "2021".to_string()
+        ]),
+        None,
+        Some(vec![]),
+        Some(vec![
+            "Grüße".to_string(),
+            "aus".to_string(),
+            "Köln".to_string()
+        ]),
+        Some(vec!["cafe\u{301}".to_string(), "noir".to_string()]),
+    ]);
+
+    let list = df_utf8_array.tokenize(true)?;
+    assert_eq!(
+        tokens(&list)[0],
+        Some(vec![
+            "hello".to_string(),
+            "world".to_string(),
+            "it's".to_string(),
+            "datafuse".to_string(),
+            "2021".to_string()
+        ])
+    );
+    assert_eq!(
+        tokens(&list)[3],
+        Some(vec![
+            "grüße".to_string(),
+            "aus".to_string(),
+            "köln".to_string()
+        ])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_utf8_like() -> Result<()> {
+    let df_utf8_array = DFUtf8Array::new_from_opt_slice(&[
+        Some("datafuse"),
+        Some("data"),
+        None,
+        Some("100%"),
+        Some("1000"),
+        Some("dàta"),
+    ]);
+
+    let result = df_utf8_array.like("data%", None)?;
+    assert_eq!(
+        vec![
+            Some(true),
+            Some(true),
+            None,
+            Some(false),
+            Some(false),
+            Some(false)
+        ],
+        result.collect_values()
+    );
+
+    let result = df_utf8_array.like("d_ta", None)?;
+    assert_eq!(
+        vec![
+            Some(false),
+            Some(true),
+            None,
+            Some(false),
+            Some(false),
+            Some(true)
+        ],
+        result.collect_values()
+    );
+
+    let result = df_utf8_array.like("%a%u%", None)?;
+    assert_eq!(
+        vec![
+            Some(true),
+            Some(false),
+            None,
+            Some(false),
+            Some(false),
+            Some(false)
+        ],
+        result.collect_values()
+    );
+
+    // An escaped % only matches a literal %
+    let result = df_utf8_array.like("1__\\%", Some('\\'))?;
+    assert_eq!(
+        vec![
+            Some(false),
+            Some(false),
+            None,
+            Some(true),
+            Some(false),
+            Some(false)
+        ],
+        result.collect_values()
+    );
+    let result = df_utf8_array.like("1__%", Some('\\'))?;
+    assert_eq!(
+        vec![
+            Some(false),
+            Some(false),
+            None,
+            Some(true),
+            Some(true),
+            Some(false)
+        ],
+        result.collect_values()
+    );
+
+    let result = df_utf8_array.like("abc\\", Some('\\'));
+    assert_eq!(
+        result.unwrap_err().message(),
+        "LIKE pattern 'abc\\' must not end with the escape character"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_utf8_jaro_winkler() -> Result<()> {
+    let lhs = DFUtf8Array::new_from_opt_slice(&[
+        Some("datafuse"),
+        Some("MARTHA"),
+        Some("DIXON"),
+        Some("abc"),
+        None,
+        Some(""),
+    ]);
+    let rhs = DFUtf8Array::new_from_opt_slice(&[
+        Some("datafuse"),
+        Some("MARHTA"),
+        Some("DICKSONX"),
+        Some("xyz"),
+        Some("abc"),
+        Some(""),
+    ]);
+
+    let scores = lhs.jaro_winkler(&rhs)?.collect_values();
+    let expected = [
+        Some(1.0),
+        Some(0.9611),
+        Some(0.8133),
+        Some(0.0),
+        None,
+        Some(1.0),
+    ];
+    for (score, expected) in scores.iter().zip(expected.iter()) {
+        match (score, expected) {
+            (Some(score), Some(expected)) => assert!((score - expected).abs() < 1e-4),
+            _ => assert_eq!(score, expected),
+        }
+    }
+
+    let result = lhs.jaro_winkler(&DFUtf8Array::new_from_slice(&["a"]));
+    assert_eq!(
+        result.unwrap_err().message(),
+        "cannot compute jaro_winkler, array size not matched, got: 1, expect: 6"
+    );
+
+    Ok(())
+}
@@ -72,7 +72,15 @@ impl VecHash for DFBooleanArray {
 impl VecHash for DFFloat32Array {
     fn vec_hash(&self, hasher: DFHasher) -> Result<DFUInt64Array> {
         Ok(self.apply_cast_numeric(|v| {
-            let v = v.to_bits();
+            // NaN has many bit patterns, canonicalize them so every NaN hashes the same,
+            // and -0.0 equals 0.0 so it must hash the same as well
+            let v = if v.is_nan() {
+                f32::NAN.to_bits()
+            } else if v == 0.0 {
+                0.0f32.to_bits()
+            } else {
+                v.to_bits()
+            };
             let mut h = hasher.clone_initial();
             v.hash(&mut h);
             h.finish()
@@ -82,7 +90,15 @@ impl VecHash for DFFloat32Array {
 impl VecHash for DFFloat64Array {
     fn vec_hash(&self, hasher: DFHasher) -> Result<DFUInt64Array> {
         Ok(self.apply_cast_numeric(|v| {
-            let v = v.to_bits();
+            // NaN has many bit patterns, canonicalize them so every NaN hashes the same,
+            // and -0.0 equals 0.0 so it must hash the same as well
+            let v = if v.is_nan() {
+                f64::NAN.to_bits()
+            } else if v == 0.0 {
+                0.0f64.to_bits()
+            } else {
+                v.to_bits()
+            };
             let mut h = hasher.clone_initial();
             v.hash(&mut h);
             h.finish()
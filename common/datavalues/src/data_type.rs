@@ -97,6 +97,10 @@ impl From<&ArrowDataType> for DataType {
                 let f: DataField = (f.as_ref()).into();
                 DataType::List(Box::new(f))
             }
+            ArrowDataType::Struct(fs) => {
+                let fs: Vec<DataField> = fs.iter().map(|f| f.into()).collect();
+                DataType::Struct(fs)
+            }
             ArrowDataType::Date32 => DataType::Date32,
             ArrowDataType::Date64 => DataType::Date64,
 
@@ -12,9 +12,11 @@ pub use crate::arrays::ArrayBuilder;
 pub use crate::arrays::ArrayCast;
 pub use crate::arrays::ArrayCompare;
 pub use crate::arrays::ArrayFillNone;
+pub use crate::arrays::ArrayFilter;
 pub use crate::arrays::ArrayFull;
 pub use crate::arrays::ArrayFullNull;
 pub use crate::arrays::ArrayScatter;
+pub use crate::arrays::ArrayScatterInto;
 pub use crate::arrays::ArrayTake;
 pub use crate::arrays::ArrayTakeEvery;
 pub use crate::arrays::BooleanArrayBuilder;
@@ -10,6 +10,8 @@ mod wrap;
 
 #[cfg(test)]
 mod arithmetic_test;
+#[cfg(test)]
+mod series_debug_test;
 
 pub use arithmetic::*;
 pub use comparison::*;
@@ -4,6 +4,7 @@
 
 use comfy_table::Cell;
 use comfy_table::Table;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
 use super::Series;
@@ -13,6 +14,47 @@ pub fn pretty_format_series(results: &[Series]) -> Result<String> {
     Ok(create_table(results)?.to_string())
 }
 
+/// Render named columns as an aligned table, showing at most `max_rows` rows.
+/// A trailing row of `...` marks that the columns were truncated.
+pub fn to_pretty_string(columns: &[(&str, &Series)], max_rows: usize) -> Result<String> {
+    let mut table = Table::new();
+    table.load_preset("||--+-++|    ++++++");
+
+    if columns.is_empty() {
+        return Ok(table.to_string());
+    }
+
+    let rows = columns[0].1.len();
+    for (name, series) in columns {
+        if series.len() != rows {
+            return Err(ErrorCode::BadArguments(format!(
+                "Column {} has {} rows, but expect: {}",
+                name,
+                series.len(),
+                rows
+            )));
+        }
+    }
+
+    let header: Vec<Cell> = columns.iter().map(|(name, _)| Cell::new(name)).collect();
+    table.set_header(header);
+
+    for row in 0..rows.min(max_rows) {
+        let mut cells = Vec::with_capacity(columns.len());
+        for (_, series) in columns {
+            let str = format!("{}", series.try_get(row)?);
+            cells.push(Cell::new(&str));
+        }
+        table.add_row(cells);
+    }
+
+    if rows > max_rows {
+        table.add_row(columns.iter().map(|_| Cell::new("...")).collect::<Vec<_>>());
+    }
+
+    Ok(table.to_string())
+}
+
 pub fn assert_series_eq(expect: Vec<&str>, series: &[Series]) {
     assert_series_eq_with_name("", expect, series)
 }
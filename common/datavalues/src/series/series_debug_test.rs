@@ -0,0 +1,49 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_exception::Result;
+
+use crate::prelude::*;
+
+#[test]
+fn test_to_pretty_string() -> Result<()> {
+    let a = Series::new(vec![1u16, 2, 3]);
+    let b = Series::new(vec![Some("x"), None, Some("zz")]);
+    let columns = [("a", &a), ("b", &b)];
+
+    let formatted = to_pretty_string(&columns, 3)?;
+    let actual: Vec<&str> = formatted.trim().lines().collect();
+    let expected = vec![
+        "+---+------+",
+        "| a | b    |",
+        "+---+------+",
+        "| 1 | x    |",
+        "| 2 | NULL |",
+        "| 3 | zz   |",
+        "+---+------+",
+    ];
+    assert_eq!(expected, actual);
+
+    let formatted = to_pretty_string(&columns, 2)?;
+    let actual: Vec<&str> = formatted.trim().lines().collect();
+    let expected = vec![
+        "+-----+------+",
+        "| a   | b    |",
+        "+-----+------+",
+        "| 1   | x    |",
+        "| 2   | NULL |",
+        "| ... | ...  |",
+        "+-----+------+",
+    ];
+    assert_eq!(expected, actual);
+
+    let c = Series::new(vec![1u16]);
+    let result = to_pretty_string(&[("a", &a), ("c", &c)], 2);
+    assert_eq!(
+        "Column c has 1 rows, but expect: 3",
+        result.unwrap_err().message()
+    );
+
+    Ok(())
+}
@@ -129,6 +129,28 @@ fn test_siphash_function() -> Result<()> {
             .into(),
             error: "",
         },
+        Test {
+            name: "Float32Array siphash with -0.0 and NaN",
+            input_column: Series::new(vec![0.0f32, -0.0, f32::NAN]).into(),
+            expect_output_column: Series::new(vec![
+                14709398186846620400u64,
+                14709398186846620400,
+                10151073798290294579,
+            ])
+            .into(),
+            error: "",
+        },
+        Test {
+            name: "Float64Array siphash with -0.0 and NaN",
+            input_column: Series::new(vec![0.0f64, -0.0, f64::NAN]).into(),
+            expect_output_column: Series::new(vec![
+                13646096770106105413u64,
+                13646096770106105413,
+                16814197991385295349,
+            ])
+            .into(),
+            error: "",
+        },
     ];
 
     for test in tests {